@@ -1,7 +1,7 @@
 use crate::{
     error::{SeriaError, SeriaResult},
     gateway::{GatewayClient, GatewayConfig},
-    http::{HttpClient, HttpConfig},
+    http::{HttpClient, HttpConfig, RetryPolicy, TokenKind},
 };
 
 /// Represents the main Seria client.
@@ -29,17 +29,51 @@ impl SeriaClient {
 #[derive(Clone, Debug)]
 pub struct SeriaClientBuilder {
     token: Option<String>,
+    token_kind: TokenKind,
+    retry: Option<RetryPolicy>,
+    autumn_url: Option<String>,
+}
+
+impl Default for SeriaClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SeriaClientBuilder {
     /// Create a new builder.
     pub fn new() -> Self {
-        SeriaClientBuilder { token: None }
+        SeriaClientBuilder {
+            token: None,
+            token_kind: TokenKind::default(),
+            retry: None,
+            autumn_url: None,
+        }
     }
 
     /// The bot token.
     pub fn token(mut self, token: impl Into<String>) -> Self {
         self.token = Some(token.into());
+        self.token_kind = TokenKind::Bot;
+        self
+    }
+
+    /// The session token of a user account, to authenticate as that user instead of a bot.
+    pub fn session_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self.token_kind = TokenKind::Session;
+        self
+    }
+
+    /// The policy used to retry failed HTTP requests.
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// The URL of the Autumn file server uploads are sent to.
+    pub fn autumn_url(mut self, url: impl Into<String>) -> Self {
+        self.autumn_url = Some(url.into());
         self
     }
 
@@ -49,7 +83,14 @@ impl SeriaClientBuilder {
             .token
             .ok_or_else(|| SeriaError::Other("Token must be provided".into()))?;
 
-        let http_config = HttpConfig::new(&token)?;
+        let mut http_config = HttpConfig::new(&token)?;
+        http_config.token_kind = self.token_kind;
+        if let Some(retry) = self.retry {
+            http_config = http_config.retry(retry);
+        }
+        if let Some(autumn_url) = self.autumn_url {
+            http_config = http_config.autumn_url(autumn_url);
+        }
         let gateway_config = GatewayConfig::new(&token)?;
 
         Ok(SeriaClient {
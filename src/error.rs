@@ -1,7 +1,7 @@
 use {
-    reqwest::{Error as ReqwestError, Response},
+    reqwest::{Error as ReqwestError, Method, Response, StatusCode},
     serde::Deserialize,
-    std::result::Result as StdResult,
+    std::{result::Result as StdResult, sync::Arc},
     thiserror::Error,
     tokio_tungstenite::tungstenite::Error as WebSocketError,
 };
@@ -9,19 +9,19 @@ use {
 pub type SeriaResult<T = (), E = SeriaError> = StdResult<T, E>;
 
 /// Top-level error type for all operations within the `seria` crate.
-#[derive(Debug, Error)]
+#[derive(Clone, Debug, Error)]
 pub enum SeriaError {
     /// Network or HTTP-related error via `reqwest`.
     #[error("HTTP error: {0}")]
-    Http(#[from] ReqwestError),
+    Http(#[source] Arc<ReqwestError>),
 
-    /// Received a response with a non-success status code.
-    #[error("Request failed with non-success status: {0:?}")]
-    FailedRequest(Response),
+    /// The Revolt API rejected the request.
+    #[error(transparent)]
+    Api(#[from] ApiError),
 
     /// WebSocket-level error.
     #[error("WebSocket error: {0}")]
-    WebSocket(#[from] WebSocketError),
+    WebSocket(#[source] Arc<WebSocketError>),
 
     /// Error encountered during authentication with the Revolt API.
     #[error("Authentication failure: {0}")]
@@ -32,6 +32,292 @@ pub enum SeriaError {
     Other(String),
 }
 
+impl From<ReqwestError> for SeriaError {
+    fn from(error: ReqwestError) -> Self {
+        SeriaError::Http(Arc::new(error))
+    }
+}
+
+impl From<WebSocketError> for SeriaError {
+    fn from(error: WebSocketError) -> Self {
+        SeriaError::WebSocket(Arc::new(error))
+    }
+}
+
+/// A request which the Revolt API answered with a non-success status.
+#[derive(Clone, Debug, Error)]
+#[error("{method} {route} failed with {status}: {kind}")]
+pub struct ApiError {
+    /// The status code of the response.
+    pub status: StatusCode,
+    /// The method of the failed request.
+    pub method: Method,
+    /// The route of the failed request.
+    pub route: String,
+    /// The decoded error returned by the API.
+    pub kind: ApiErrorKind,
+}
+
+impl ApiError {
+    /// Decode the error carried by a non-success response.
+    pub(crate) async fn from_response(
+        method: Method,
+        route: String,
+        response: Response,
+    ) -> Result<Self, ReqwestError> {
+        let status = response.status();
+        let body = response.bytes().await?;
+        let kind = ApiErrorKind::from_body(&body);
+
+        Ok(ApiError {
+            status,
+            method,
+            route,
+            kind,
+        })
+    }
+}
+
+/// The error types the Revolt API reports in the `type` field of an error response.
+#[derive(Clone, Debug, Deserialize, Error, PartialEq)]
+#[serde(tag = "type")]
+pub enum ApiErrorKind {
+    /// An error the API has not categorised yet.
+    #[error("Uncategorized error")]
+    LabelMe,
+
+    // Onboarding errors
+    /// The account already chose a username.
+    #[error("Account has already been onboarded")]
+    AlreadyOnboarded,
+
+    // User errors
+    /// The requested username is used by another user.
+    #[error("Username is already taken")]
+    UsernameTaken,
+    /// The requested username contains disallowed characters or words.
+    #[error("Invalid username")]
+    InvalidUsername,
+    /// The discriminator cannot be changed again yet.
+    #[error("Discriminator was changed too recently")]
+    DiscriminatorChangeRatelimited,
+    /// The targeted user does not exist.
+    #[error("Unknown user")]
+    UnknownUser,
+    /// The users are already friends.
+    #[error("Already friends with this user")]
+    AlreadyFriends,
+    /// A friend request to the user is already pending.
+    #[error("Friend request already sent")]
+    AlreadySentRequest,
+    /// The current user blocked the targeted user.
+    #[error("User is blocked")]
+    Blocked,
+    /// The targeted user blocked the current user.
+    #[error("Blocked by the other user")]
+    BlockedByOther,
+    /// The action requires the users to be friends.
+    #[error("Not friends with this user")]
+    NotFriends,
+    /// The current user has too many outgoing friend requests.
+    #[error("Too many pending friend requests (max {max})")]
+    TooManyPendingFriendRequests { max: usize },
+
+    // Channel errors
+    /// The targeted channel does not exist or is not visible.
+    #[error("Unknown channel")]
+    UnknownChannel,
+    /// A referenced attachment does not exist or was already used.
+    #[error("Unknown attachment")]
+    UnknownAttachment,
+    /// The targeted message does not exist.
+    #[error("Unknown message")]
+    UnknownMessage,
+    /// The message was not sent by the current user.
+    #[error("Cannot edit this message")]
+    CannotEditMessage,
+    /// The channel does not support voice calls.
+    #[error("Cannot join this call")]
+    CannotJoinCall,
+    /// The message has more attachments than allowed.
+    #[error("Too many attachments (max {max})")]
+    TooManyAttachments { max: usize },
+    /// The message has more embeds than allowed.
+    #[error("Too many embeds (max {max})")]
+    TooManyEmbeds { max: usize },
+    /// The message replies to more messages than allowed.
+    #[error("Too many replies (max {max})")]
+    TooManyReplies { max: usize },
+    /// The server has reached its channel limit.
+    #[error("Too many channels (max {max})")]
+    TooManyChannels { max: usize },
+    /// The message has neither content, attachments nor embeds.
+    #[error("Message is empty")]
+    EmptyMessage,
+    /// The request body exceeds the size limit.
+    #[error("Payload is too large")]
+    PayloadTooLarge,
+    /// The group owner cannot remove themselves.
+    #[error("Cannot remove yourself")]
+    CannotRemoveYourself,
+    /// The group has reached its member limit.
+    #[error("Group is too large (max {max})")]
+    GroupTooLarge { max: usize },
+    /// The user is already a member of the group.
+    #[error("Already in this group")]
+    AlreadyInGroup,
+    /// The user is not a member of the group.
+    #[error("Not in this group")]
+    NotInGroup,
+    /// The message is already pinned.
+    #[error("Message is already pinned")]
+    AlreadyPinned,
+    /// The message is not pinned.
+    #[error("Message is not pinned")]
+    NotPinned,
+
+    // Server errors
+    /// The targeted server does not exist or is not visible.
+    #[error("Unknown server")]
+    UnknownServer,
+    /// The targeted role does not exist in the server.
+    #[error("Invalid role")]
+    InvalidRole,
+    /// The user is banned from the server.
+    #[error("Banned from this server")]
+    Banned,
+    /// The user has joined the maximum number of servers.
+    #[error("Too many servers (max {max})")]
+    TooManyServers { max: usize },
+    /// The server has reached its emoji limit.
+    #[error("Too many emoji (max {max})")]
+    TooManyEmoji { max: usize },
+    /// The server has reached its role limit.
+    #[error("Too many roles (max {max})")]
+    TooManyRoles { max: usize },
+    /// The user is already a member of the server.
+    #[error("Already in this server")]
+    AlreadyInServer,
+    /// Members cannot time themselves out.
+    #[error("Cannot time out yourself")]
+    CannotTimeoutYourself,
+
+    // Bot errors
+    /// The user owns the maximum number of bots.
+    #[error("Reached the maximum number of bots")]
+    ReachedMaximumBots,
+    /// The action is not available to bots.
+    #[error("User is a bot")]
+    IsBot,
+    /// The action is only available to bots.
+    #[error("User is not a bot")]
+    IsNotBot,
+    /// The bot can only be invited by its owner.
+    #[error("Bot is private")]
+    BotIsPrivate,
+
+    // Safety errors
+    /// Users cannot report themselves or their own content.
+    #[error("Cannot report yourself")]
+    CannotReportYourself,
+
+    // Permission errors
+    /// The current user lacks a channel or server permission.
+    #[error("Missing permission {permission}")]
+    MissingPermission { permission: String },
+    /// The current user lacks a permission towards another user.
+    #[error("Missing user permission {permission}")]
+    MissingUserPermission { permission: String },
+    /// The session must be elevated for this action.
+    #[error("Session is not elevated")]
+    NotElevated,
+    /// The action requires a privileged account.
+    #[error("Not privileged")]
+    NotPrivileged,
+    /// Permissions cannot be granted beyond the ones the current user has.
+    #[error("Cannot give permissions you do not have")]
+    CannotGiveMissingPermissions,
+    /// The action is reserved to the owner.
+    #[error("Not the owner")]
+    NotOwner,
+
+    // General errors
+    /// The API failed to access its database.
+    #[error("Database error during {operation} on {collection}")]
+    DatabaseError {
+        operation: String,
+        collection: String,
+    },
+    /// The API failed unexpectedly.
+    #[error("Server encountered an internal error")]
+    InternalError,
+    /// The request is not valid in the current state.
+    #[error("Invalid operation")]
+    InvalidOperation,
+    /// The provided credentials are wrong.
+    #[error("Invalid credentials")]
+    InvalidCredentials,
+    /// A property of the request is invalid.
+    #[error("Invalid property")]
+    InvalidProperty,
+    /// The session token is invalid or expired.
+    #[error("Invalid session")]
+    InvalidSession,
+    /// A flag value is not allowed.
+    #[error("Invalid flag value")]
+    InvalidFlagValue,
+    /// The request was not authenticated.
+    #[error("Not authenticated")]
+    NotAuthenticated,
+    /// A message with the same nonce or idempotency key was already sent.
+    #[error("Duplicate nonce")]
+    DuplicateNonce,
+    /// The requested resource does not exist.
+    #[error("Not found")]
+    NotFound,
+    /// The request would not change anything.
+    #[error("Request had no effect")]
+    NoEffect,
+    /// The request body failed validation.
+    #[error("Failed validation: {error}")]
+    FailedValidation { error: String },
+
+    // Micro-service errors
+    /// The media proxy failed to fetch the resource.
+    #[error("Proxy error")]
+    ProxyError,
+    /// The uploaded file is empty.
+    #[error("File is too small")]
+    FileTooSmall,
+    /// The uploaded file exceeds the size limit of its tag.
+    #[error("File is too large (max {max} bytes)")]
+    FileTooLarge { max: usize },
+    /// The uploaded file type is not allowed for its tag.
+    #[error("File type is not allowed")]
+    FileTypeNotAllowed,
+    /// The uploaded image could not be processed.
+    #[error("Image processing failed")]
+    ImageProcessingFailed,
+    /// No embed could be generated for the URL.
+    #[error("No embed data available")]
+    NoEmbedData,
+
+    /// An error which is not known to this version of the crate, or an undecodable body.
+    #[error("Unknown error")]
+    #[serde(other)]
+    Unknown,
+}
+
+impl ApiErrorKind {
+    /// Decode the body of an error response, falling back to [`ApiErrorKind::Unknown`].
+    pub(crate) fn from_body(body: &[u8]) -> Self {
+        serde_json::from_slice(body).unwrap_or_else(|_| {
+            tracing::debug!("Undecodable error body: {}", String::from_utf8_lossy(body));
+            ApiErrorKind::Unknown
+        })
+    }
+}
+
 /// Authentication-specific errors encountered during login or token validation.
 #[derive(Debug, Error, Deserialize, Clone, Copy, PartialEq)]
 pub enum AuthError {
@@ -54,3 +340,49 @@ pub enum AuthError {
     #[error("Session already active")]
     AlreadyAuthenticated,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_variant_with_fields() {
+        let kind = ApiErrorKind::from_body(
+            br#"{"type":"MissingPermission","permission":"ManageChannel"}"#,
+        );
+
+        assert_eq!(
+            kind,
+            ApiErrorKind::MissingPermission {
+                permission: "ManageChannel".into()
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_unit_variant() {
+        let kind = ApiErrorKind::from_body(br#"{"type":"UnknownChannel"}"#);
+
+        assert_eq!(kind, ApiErrorKind::UnknownChannel);
+    }
+
+    #[test]
+    fn unknown_type_falls_back_to_unknown() {
+        let kind = ApiErrorKind::from_body(br#"{"type":"SomethingNew","detail":1}"#);
+
+        assert_eq!(kind, ApiErrorKind::Unknown);
+    }
+
+    #[test]
+    fn undecodable_body_falls_back_to_unknown() {
+        assert_eq!(
+            ApiErrorKind::from_body(b"<html>Bad Gateway</html>"),
+            ApiErrorKind::Unknown
+        );
+        assert_eq!(ApiErrorKind::from_body(b""), ApiErrorKind::Unknown);
+        assert_eq!(
+            ApiErrorKind::from_body(br#"{"type":"TooManyEmbeds"}"#),
+            ApiErrorKind::Unknown
+        );
+    }
+}
@@ -130,7 +130,7 @@ impl GatewayClient {
 
                     if let Err(e) = write_stream.send(msg).await {
                         let _ = server_sender
-                            .send(Err(handle_websocket_error(e).into()))
+                            .send(Err(handle_websocket_error(e)))
                             .await;
                         break;
                     }
@@ -159,7 +159,7 @@ impl GatewayClient {
                             }
                             _ => continue,
                         },
-                        Err(e) => Err(handle_websocket_error(e).into()),
+                        Err(e) => Err(handle_websocket_error(e)),
                     };
 
                     if server_sender.send(event).await.is_err() {
@@ -192,7 +192,7 @@ impl GatewayClient {
         if last_ping > last_pong {
             last_ping - last_pong
         } else {
-            last_pong - last_pong
+            last_pong - last_ping
         }
     }
 
@@ -216,7 +216,7 @@ fn handle_websocket_error(err: WsError) -> SeriaError {
         WsError::Io(io_err) if io_err.raw_os_error() == Some(10054) => {
             SeriaError::Other("Connection forcibly closed by remote host".to_string())
         }
-        _ => SeriaError::WebSocket(err),
+        _ => SeriaError::from(err),
     }
 }
 
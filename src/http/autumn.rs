@@ -0,0 +1,279 @@
+//! Uploads to Autumn, the file server issuing the IDs which attachments, avatars,
+//! icons and emojis are referenced by.
+
+use {
+    bytes::Bytes,
+    futures::{stream::BoxStream, Stream, StreamExt, TryStreamExt},
+    reqwest::{
+        header::{CONTENT_LENGTH, CONTENT_TYPE},
+        multipart::{Form, Part},
+        Body, Client, Method,
+    },
+    serde::Deserialize,
+    std::{error::Error as StdError, fmt, path::PathBuf},
+    tokio::io::{AsyncWrite, AsyncWriteExt},
+};
+
+use crate::{
+    error::{ApiError, SeriaError, SeriaResult},
+    http::HttpConfig,
+    models::{Attachment, AttachmentTag, Id},
+};
+
+type BoxError = Box<dyn StdError + Send + Sync>;
+
+/// The body of a successful upload.
+#[derive(Deserialize)]
+struct Uploaded {
+    id: Id,
+}
+
+/// A client for the Autumn file server, sharing the credentials of an [`HttpClient`](crate::http::HttpClient).
+#[derive(Clone, Debug)]
+pub struct AutumnClient {
+    client: Client,
+    config: HttpConfig,
+}
+
+impl AutumnClient {
+    pub(crate) fn new(client: Client, config: HttpConfig) -> Self {
+        AutumnClient { client, config }
+    }
+
+    /// Upload a file with the given tag and return its ID.
+    ///
+    /// Files larger than the limit of the tag are rejected before being sent when their
+    /// size is known up front, and aborted once the limit is crossed otherwise.
+    pub async fn upload(&self, tag: AttachmentTag, file: FileUpload) -> SeriaResult<Id> {
+        let max = tag.max_size();
+        let check = |size: u64| {
+            if size > max {
+                return Err(SeriaError::Other(format!(
+                    "File of {} bytes exceeds the {} byte limit of {}",
+                    size,
+                    max,
+                    tag.as_str()
+                )));
+            }
+            Ok(())
+        };
+
+        let (part, filename) = match file.data {
+            FileData::Bytes(bytes) => {
+                check(bytes.len() as u64)?;
+                let length = bytes.len() as u64;
+                (Part::stream_with_length(bytes, length), file.filename)
+            }
+            FileData::Path(path) => {
+                let contents = tokio::fs::File::open(&path).await.map_err(io_error)?;
+                let length = contents.metadata().await.map_err(io_error)?.len();
+                check(length)?;
+                let filename = file.filename.or_else(|| {
+                    path.file_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                });
+                (
+                    Part::stream_with_length(Body::from(contents), length),
+                    filename,
+                )
+            }
+            FileData::Stream(stream) => {
+                let mut total = 0u64;
+                let limited = stream.map(move |chunk| {
+                    let chunk = chunk?;
+                    total += chunk.len() as u64;
+                    if total > max {
+                        return Err(BoxError::from(format!(
+                            "File exceeds the {} byte limit of {}",
+                            max,
+                            tag.as_str()
+                        )));
+                    }
+                    Ok(chunk)
+                });
+                (Part::stream(Body::wrap_stream(limited)), file.filename)
+            }
+        };
+
+        let mut part = part.file_name(filename.unwrap_or_else(|| "file".to_string()));
+        if let Some(content_type) = &file.content_type {
+            part = part.mime_str(content_type)?;
+        }
+
+        let route = format!("/{}", tag.as_str());
+        let url = format!("{}{}", self.config.autumn_url.trim_end_matches('/'), route);
+        let response = self
+            .client
+            .post(url)
+            .multipart(Form::new().part("file", part))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let error = ApiError::from_response(Method::POST, route, response).await?;
+
+            return Err(error.into());
+        }
+
+        Ok(response.json::<Uploaded>().await?.id)
+    }
+
+    /// Download an attachment into the writer and return the number of bytes written.
+    ///
+    /// The progress callback receives the bytes written so far and the expected total after
+    /// every chunk. The download fails if the served content type or size does not match the
+    /// attachment.
+    pub async fn download<W>(
+        &self,
+        attachment: &Attachment,
+        writer: &mut W,
+        mut progress: impl FnMut(u64, u64),
+    ) -> SeriaResult<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let url = attachment.url(&self.config);
+        let response = self.client.get(&url).send().await?;
+
+        if !response.status().is_success() {
+            let route = format!("/{}/{}", attachment.tag.as_str(), attachment.id);
+            let error = ApiError::from_response(Method::GET, route, response).await?;
+
+            return Err(error.into());
+        }
+
+        let expected = attachment.size as u64;
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+        };
+
+        if let Some(content_type) = header(CONTENT_TYPE) {
+            let essence = content_type.split(';').next().unwrap_or_default().trim();
+            if !essence.eq_ignore_ascii_case(&attachment.content_type) {
+                return Err(SeriaError::Other(format!(
+                    "Attachment {} was served as {} instead of {}",
+                    attachment.id, essence, attachment.content_type
+                )));
+            }
+        }
+
+        if let Some(length) = header(CONTENT_LENGTH).and_then(|value| value.parse::<u64>().ok()) {
+            if length != expected {
+                return Err(size_mismatch(attachment, length));
+            }
+        }
+
+        let mut written = 0u64;
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.try_next().await? {
+            written += chunk.len() as u64;
+            if written > expected {
+                return Err(size_mismatch(attachment, written));
+            }
+
+            writer.write_all(&chunk).await.map_err(|error| {
+                SeriaError::Other(format!("Failed to write attachment: {}", error))
+            })?;
+            progress(written, expected);
+        }
+
+        if written != expected {
+            return Err(size_mismatch(attachment, written));
+        }
+
+        writer
+            .flush()
+            .await
+            .map_err(|error| SeriaError::Other(format!("Failed to write attachment: {}", error)))?;
+
+        Ok(written)
+    }
+}
+
+fn size_mismatch(attachment: &Attachment, size: u64) -> SeriaError {
+    SeriaError::Other(format!(
+        "Attachment {} was served with {} bytes instead of {}",
+        attachment.id, size, attachment.size
+    ))
+}
+
+fn io_error(error: std::io::Error) -> SeriaError {
+    SeriaError::Other(format!("Failed to read file: {}", error))
+}
+
+/// A file to upload, read from memory, the filesystem or a stream.
+pub struct FileUpload {
+    filename: Option<String>,
+    content_type: Option<String>,
+    data: FileData,
+}
+
+enum FileData {
+    Bytes(Bytes),
+    Path(PathBuf),
+    Stream(BoxStream<'static, Result<Bytes, BoxError>>),
+}
+
+impl FileUpload {
+    /// A file held in memory.
+    pub fn bytes(filename: impl Into<String>, data: impl Into<Bytes>) -> Self {
+        FileUpload {
+            filename: Some(filename.into()),
+            content_type: None,
+            data: FileData::Bytes(data.into()),
+        }
+    }
+
+    /// A file on disk, streamed when it is uploaded and named after its path.
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        FileUpload {
+            filename: None,
+            content_type: None,
+            data: FileData::Path(path.into()),
+        }
+    }
+
+    /// A file streamed in chunks, for files which should not be held in memory.
+    pub fn stream<S, E>(filename: impl Into<String>, stream: S) -> Self
+    where
+        S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+        E: Into<BoxError> + 'static,
+    {
+        FileUpload {
+            filename: Some(filename.into()),
+            content_type: None,
+            data: FileData::Stream(stream.map_err(Into::into).boxed()),
+        }
+    }
+
+    /// The name of the file, overriding the one taken from its path.
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// The MIME type of the file, otherwise detected by the server.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+}
+
+impl fmt::Debug for FileUpload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data = match &self.data {
+            FileData::Bytes(bytes) => format!("{} bytes", bytes.len()),
+            FileData::Path(path) => path.display().to_string(),
+            FileData::Stream(_) => "stream".to_string(),
+        };
+
+        f.debug_struct("FileUpload")
+            .field("filename", &self.filename)
+            .field("content_type", &self.content_type)
+            .field("data", &data)
+            .finish()
+    }
+}
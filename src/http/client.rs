@@ -1,134 +1,397 @@
 use {
+    bytes::Bytes,
+    futures::{stream, Stream, TryStreamExt},
     reqwest::{
         header::{HeaderMap, HeaderValue},
-        Client,
+        Client, Method, RequestBuilder, Response, StatusCode,
     },
-    serde::{de::DeserializeOwned, ser::Serialize},
+    serde::{de::DeserializeOwned, ser::Serialize, Deserialize},
+    std::{
+        collections::HashMap,
+        pin::pin,
+        sync::{Arc, Mutex},
+        time::{Duration, SystemTime},
+    },
+    tokio::{io::AsyncWrite, time::sleep},
 };
 
 use crate::{
-    error::{SeriaError, SeriaResult},
-    http::{endpoint::Endpoint, HttpConfig},
+    error::{ApiError, SeriaError, SeriaResult},
+    http::{endpoint::Endpoint, ratelimit::RateLimiter, AutumnClient, FileUpload, HttpConfig},
     models::{
-        FlagResponse, Message, MessageEdit, MessageReplyIntent, MessageSend, PublicBot, User,
-        UserUpdate,
+        id_timestamp, Attachment, AttachmentTag, BanCreate, BanList, Bot, BotCreate, BotEdit,
+        BotInviteDestination, BotResponse, BulkMessageDelete, BulkMessageResponse, Channel,
+        ChannelCreate, ChannelUnread, ChannelUpdate, ContentReport, DefaultPermissions, Emoji,
+        EmojiCreate, FlagResponse, Id, Invite, InviteInfo, InviteJoin, Member, MemberEdit,
+        MemberList, Message, MessageEdit, MessageQuery, MessageReplyIntent, MessageSearch,
+        MessageSend, MessageSort, MutualResponse, Override, OwnedBots, Permission,
+        PermissionsUpdate, PublicBot, PurgeFilter, ReactionRemove, Role, RoleCreate,
+        RoleCreateResponse, RoleEdit, SendFriendRequest, Server, ServerBan, ServerCreate,
+        ServerCreateResponse, ServerEdit, User, UserProfile, UserUpdate, Webhook, WebhookCreate,
+        WebhookEdit,
     },
 };
 
+/// The maximum number of messages the API returns per page.
+const MESSAGE_PAGE_LIMIT: u32 = 100;
+
+/// The maximum number of messages which can be deleted in a single request.
+const BULK_DELETE_LIMIT: usize = 100;
+
+/// The maximum age of messages which can be deleted in bulk.
+const BULK_DELETE_MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// The header marking a request as safe to retry, as the API discards its duplicates.
+const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+
+/// The body of a response rejected by the rate limiter.
+#[derive(Deserialize)]
+struct RateLimited {
+    /// Milliseconds until the bucket resets.
+    retry_after: u64,
+}
+
+/// Decode a response body which may be empty, in which case it decodes as `()`.
+async fn decode<T: DeserializeOwned>(response: Response) -> SeriaResult<T> {
+    let body = response.bytes().await?;
+    let body: &[u8] = if body.is_empty() { b"null" } else { &body };
+
+    serde_json::from_slice(body)
+        .map_err(|e| SeriaError::Other(format!("Deserialization error: {}", e)))
+}
+
 #[derive(Debug, Clone)]
 pub struct HttpClient {
     client: Client,
     config: HttpConfig,
+    ratelimiter: RateLimiter,
+    autumn: AutumnClient,
+    /// The direct message channels already opened, by the ID of the other user.
+    dm_channels: Arc<Mutex<HashMap<Id, Id>>>,
 }
 
 impl HttpClient {
     pub fn new(config: HttpConfig) -> Self {
         let mut headers = HeaderMap::new();
-        headers.insert("X-Bot-Token", HeaderValue::from_str(&config.token).unwrap());
+        if !config.token.is_empty() {
+            headers.insert(
+                config.token_kind.header(),
+                HeaderValue::from_str(&config.token).unwrap(),
+            );
+        }
 
         let client = Client::builder().default_headers(headers).build().unwrap();
 
-        Self { client, config }
+        Self {
+            autumn: AutumnClient::new(client.clone(), config.clone()),
+            client,
+            config,
+            ratelimiter: RateLimiter::default(),
+            dm_channels: Arc::default(),
+        }
+    }
+
+    /// The configuration this client was created with.
+    pub fn config(&self) -> &HttpConfig {
+        &self.config
+    }
+
+    /// The URL of an attachment on the configured Autumn file server, optionally resized so
+    /// that its longest side is at most `max_side` pixels.
+    pub fn attachment_url(&self, attachment: &Attachment, max_side: Option<u32>) -> String {
+        match max_side {
+            Some(max_side) => attachment.resized_url(&self.config, max_side),
+            None => attachment.url(&self.config),
+        }
+    }
+
+    /// The client for the Autumn file server, authenticated like this client.
+    pub fn autumn(&self) -> &AutumnClient {
+        &self.autumn
+    }
+
+    /// Upload a file to the Autumn file server and return the ID to reference it by.
+    pub async fn upload_file(&self, tag: AttachmentTag, file: FileUpload) -> SeriaResult<Id> {
+        self.autumn.upload(tag, file).await
+    }
+
+    /// Download an attachment into the writer, verifying its content type and size.
+    ///
+    /// The progress callback receives the bytes written so far and the expected total.
+    pub async fn download_attachment<W>(
+        &self,
+        attachment: &Attachment,
+        writer: &mut W,
+        progress: impl FnMut(u64, u64),
+    ) -> SeriaResult<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        self.autumn.download(attachment, writer, progress).await
     }
 
     fn make_url(&self, path: impl AsRef<str>) -> String {
         format!(
             "{}/{}",
             self.config.api_url.trim_end_matches('/'),
-            path.as_ref()
+            path.as_ref().trim_start_matches('/')
         )
     }
 
-    pub async fn get<T: DeserializeOwned>(&self, path: impl AsRef<str>) -> SeriaResult<T> {
-        let response = self.client.get(self.make_url(path)).send().await?;
+    /// Send a request to the endpoint, waiting for its rate limit bucket when exhausted.
+    ///
+    /// Requests rejected with `429 Too Many Requests` are queued again until the bucket resets,
+    /// other failures are retried as described by the configured [`RetryPolicy`](crate::http::RetryPolicy).
+    /// Requests which are not idempotent are only retried when they carry an `Idempotency-Key`.
+    async fn request(
+        &self,
+        method: Method,
+        endpoint: Endpoint,
+        build: impl FnOnce(RequestBuilder) -> RequestBuilder,
+    ) -> SeriaResult<Response> {
+        let bucket = endpoint.bucket(&method);
+        let url = self.make_url(endpoint.path());
+        let request = build(self.client.request(method.clone(), url)).build()?;
+
+        let retry = &self.config.retry;
+        let retryable =
+            retry.is_retryable_method(&method) || request.headers().contains_key(IDEMPOTENCY_KEY);
+        let mut attempt = 1;
+
+        loop {
+            let cloned = request
+                .try_clone()
+                .ok_or_else(|| SeriaError::Other("Request body cannot be cloned".into()))?;
+
+            self.ratelimiter.acquire(&bucket).await;
+            let response = match self.client.execute(cloned).await {
+                Ok(response) => response,
+                Err(error)
+                    if retryable
+                        && attempt < retry.max_attempts
+                        && retry.is_retryable_error(&error) =>
+                {
+                    let delay = retry.delay(attempt);
+                    tracing::warn!(
+                        "Request to {} failed ({}), retrying in {:?}",
+                        endpoint.path(),
+                        error,
+                        delay
+                    );
+                    sleep(delay).await;
+                    attempt += 1;
+                    continue;
+                }
+                Err(error) => return Err(error.into()),
+            };
+            self.ratelimiter.update(&bucket, response.headers());
+
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                let retry_after = response
+                    .json::<RateLimited>()
+                    .await
+                    .map(|body| Duration::from_millis(body.retry_after))
+                    .unwrap_or(Duration::from_secs(1));
 
-        if !response.status().is_success() {
-            return Err(SeriaError::FailedRequest(response));
+                tracing::warn!(
+                    "Rate limited on bucket {}, retrying in {:?}",
+                    bucket,
+                    retry_after
+                );
+                self.ratelimiter.exhaust(&bucket, retry_after);
+                continue;
+            }
+
+            if !response.status().is_success() {
+                if retryable
+                    && attempt < retry.max_attempts
+                    && retry.is_retryable_status(response.status())
+                {
+                    let delay = retry.delay(attempt);
+                    tracing::warn!(
+                        "Request to {} failed with {}, retrying in {:?}",
+                        endpoint.path(),
+                        response.status(),
+                        delay
+                    );
+                    sleep(delay).await;
+                    attempt += 1;
+                    continue;
+                }
+
+                let error = ApiError::from_response(method, endpoint.path(), response).await?;
+
+                return Err(error.into());
+            }
+
+            return Ok(response);
         }
+    }
 
-        let payload = response.json().await?;
+    pub async fn get<T: DeserializeOwned>(&self, endpoint: Endpoint) -> SeriaResult<T> {
+        let response = self
+            .request(Method::GET, endpoint, |request| request)
+            .await?;
 
-        Ok(payload)
+        decode(response).await
+    }
+
+    pub async fn get_query<T: DeserializeOwned, Q: Serialize>(
+        &self,
+        endpoint: Endpoint,
+        query: &Q,
+    ) -> SeriaResult<T> {
+        let response = self
+            .request(Method::GET, endpoint, |request| request.query(query))
+            .await?;
+
+        decode(response).await
     }
 
     pub async fn post<T: DeserializeOwned, U: Serialize>(
         &self,
-        path: impl AsRef<str>,
+        endpoint: Endpoint,
         payload: U,
     ) -> SeriaResult<T> {
         let response = self
-            .client
-            .post(self.make_url(path))
-            .json(&payload)
-            .send()
+            .request(Method::POST, endpoint, |request| request.json(&payload))
             .await?;
 
-        if !response.status().is_success() {
-            return Err(SeriaError::FailedRequest(response));
-        }
+        decode(response).await
+    }
 
-        let payload = response.json().await?;
+    /// Send a POST request with a fresh `Idempotency-Key`, so that it can be retried safely.
+    pub async fn post_idempotent<T: DeserializeOwned, U: Serialize>(
+        &self,
+        endpoint: Endpoint,
+        payload: U,
+    ) -> SeriaResult<T> {
+        let key = format!("{:032x}", fastrand::u128(..));
+        let response = self
+            .request(Method::POST, endpoint, |request| {
+                request.header(IDEMPOTENCY_KEY, key).json(&payload)
+            })
+            .await?;
 
-        Ok(payload)
+        decode(response).await
     }
 
-    pub async fn put<T: Serialize>(&self, path: impl AsRef<str>, payload: T) -> SeriaResult {
+    pub async fn post_empty<T: DeserializeOwned>(&self, endpoint: Endpoint) -> SeriaResult<T> {
         let response = self
-            .client
-            .put(self.make_url(path))
-            .json(&payload)
-            .send()
+            .request(Method::POST, endpoint, |request| request)
             .await?;
 
-        if !response.status().is_success() {
-            return Err(SeriaError::FailedRequest(response));
-        }
+        decode(response).await
+    }
 
-        Ok(())
+    pub async fn put<T: Serialize, R: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        payload: T,
+    ) -> SeriaResult<R> {
+        let response = self
+            .request(Method::PUT, endpoint, |request| request.json(&payload))
+            .await?;
+
+        decode(response).await
+    }
+
+    pub async fn put_empty<T: DeserializeOwned>(&self, endpoint: Endpoint) -> SeriaResult<T> {
+        let response = self
+            .request(Method::PUT, endpoint, |request| request)
+            .await?;
+
+        decode(response).await
     }
 
     pub async fn patch<T: Serialize, R: DeserializeOwned>(
         &self,
-        path: impl AsRef<str>,
+        endpoint: Endpoint,
         payload: T,
     ) -> SeriaResult<R> {
         let response = self
-            .client
-            .patch(self.make_url(path))
-            .json(&payload)
-            .send()
+            .request(Method::PATCH, endpoint, |request| request.json(&payload))
             .await?;
 
-        if !response.status().is_success() {
-            return Err(SeriaError::FailedRequest(response));
-        }
+        decode(response).await
+    }
 
-        let payload = response.json().await?;
+    pub async fn delete_json<T: Serialize>(&self, endpoint: Endpoint, payload: T) -> SeriaResult {
+        self.request(Method::DELETE, endpoint, |request| request.json(&payload))
+            .await?;
 
-        Ok(payload)
+        Ok(())
     }
 
-    pub async fn delete(&self, path: impl AsRef<str>) -> SeriaResult {
-        let response = self.client.delete(self.make_url(path)).send().await?;
-
-        if !response.status().is_success() {
-            return Err(SeriaError::FailedRequest(response));
-        }
+    pub async fn delete_query<Q: Serialize>(&self, endpoint: Endpoint, query: &Q) -> SeriaResult {
+        self.request(Method::DELETE, endpoint, |request| request.query(query))
+            .await?;
 
         Ok(())
     }
 
+    pub async fn delete<T: DeserializeOwned>(&self, endpoint: Endpoint) -> SeriaResult<T> {
+        let response = self
+            .request(Method::DELETE, endpoint, |request| request)
+            .await?;
+
+        decode(response).await
+    }
+
     // Bot-related methods
     /// Get a public bot.
     pub async fn get_public_bot(&self, bot_id: &str) -> SeriaResult<PublicBot> {
-        self.get(Endpoint::BotInvite(bot_id.to_string()).path())
+        self.get(Endpoint::BotInvite(bot_id.to_string())).await
+    }
+
+    /// Create a new bot owned by the current user.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn create_bot(&self, payload: impl Into<BotCreate>) -> SeriaResult<Bot> {
+        self.post(Endpoint::BotCreate(), payload.into()).await
+    }
+
+    /// Get a bot owned by the current user, along with its user.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn fetch_bot(&self, bot_id: &str) -> SeriaResult<BotResponse> {
+        self.get(Endpoint::Bot(bot_id.to_string())).await
+    }
+
+    /// Get all bots owned by the current user.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn fetch_owned_bots(&self) -> SeriaResult<OwnedBots> {
+        self.get(Endpoint::Bot("@me".to_string())).await
+    }
+
+    /// Edit a bot, or reset its token.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn edit_bot(&self, bot_id: &str, payload: impl Into<BotEdit>) -> SeriaResult<Bot> {
+        self.patch(Endpoint::Bot(bot_id.to_string()), payload.into())
+            .await
+    }
+
+    /// Delete a bot.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn delete_bot(&self, bot_id: &str) -> SeriaResult {
+        self.delete(Endpoint::Bot(bot_id.to_string())).await
+    }
+
+    /// Invite a bot to a server or group.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn invite_bot(&self, bot_id: &str, destination: BotInviteDestination) -> SeriaResult {
+        self.post(Endpoint::BotInvite(bot_id.to_string()), destination)
             .await
     }
 
     // User-related methods
     /// Get properties of the bot user.
     pub async fn get_self(&self) -> SeriaResult<User> {
-        self.get(Endpoint::User("@me".to_string()).path()).await
+        self.get(Endpoint::User("@me".to_string())).await
     }
 
     /// Edit a user.
@@ -137,21 +400,198 @@ impl HttpClient {
         user_id: &str,
         payload: impl Into<UserUpdate>,
     ) -> SeriaResult<User> {
-        self.patch(Endpoint::User(user_id.to_string()).path(), payload.into())
+        self.patch(Endpoint::User(user_id.to_string()), payload.into())
             .await
     }
 
     /// Get properties of the targeted user.
     pub async fn get_user(&self, user_id: &str) -> SeriaResult<User> {
-        self.get(Endpoint::User(user_id.to_string()).path()).await
+        self.get(Endpoint::User(user_id.to_string())).await
     }
 
     /// Get the flags of the targeted user.
     pub async fn get_user_flags(&self, user_id: &str) -> SeriaResult<FlagResponse> {
-        self.get(Endpoint::UserFlags(user_id.to_string()).path())
+        self.get(Endpoint::UserFlags(user_id.to_string())).await
+    }
+
+    /// Get the profile of the targeted user.
+    pub async fn fetch_user_profile(&self, user_id: &str) -> SeriaResult<UserProfile> {
+        self.get(Endpoint::UserProfile(user_id.to_string())).await
+    }
+
+    /// Download the default avatar of the targeted user, as a PNG image.
+    pub async fn fetch_default_avatar(&self, user_id: &str) -> SeriaResult<Bytes> {
+        let response = self
+            .request(
+                Method::GET,
+                Endpoint::UserDefaultAvatar(user_id.to_string()),
+                |request| request,
+            )
+            .await?;
+
+        Ok(response.bytes().await?)
+    }
+
+    // Channel-related methods
+    /// Get a channel.
+    pub async fn fetch_channel(&self, channel_id: &str) -> SeriaResult<Channel> {
+        self.get(Endpoint::Channel(channel_id.to_string())).await
+    }
+
+    /// Create a new channel in the specified server.
+    pub async fn create_channel(
+        &self,
+        server_id: &str,
+        payload: impl Into<ChannelCreate>,
+    ) -> SeriaResult<Channel> {
+        self.post(
+            Endpoint::ServerChannels(server_id.to_string()),
+            payload.into(),
+        )
+        .await
+    }
+
+    /// Edit a channel.
+    pub async fn edit_channel(
+        &self,
+        channel_id: &str,
+        payload: impl Into<ChannelUpdate>,
+    ) -> SeriaResult<Channel> {
+        self.patch(Endpoint::Channel(channel_id.to_string()), payload.into())
+            .await
+    }
+
+    /// Set the permissions granted and denied to a role in a channel.
+    pub async fn set_channel_role_permissions(
+        &self,
+        channel_id: &str,
+        role_id: &str,
+        permissions: impl Into<Override>,
+    ) -> SeriaResult<Channel> {
+        self.put(
+            Endpoint::ChannelPermission(channel_id.to_string(), role_id.to_string()),
+            PermissionsUpdate {
+                permissions: permissions.into(),
+            },
+        )
+        .await
+    }
+
+    /// Set the default permissions of a channel.
+    ///
+    /// Groups take a plain [`Permission`], server channels take an [`Override`].
+    pub async fn set_channel_default_permissions(
+        &self,
+        channel_id: &str,
+        permissions: impl Into<DefaultPermissions>,
+    ) -> SeriaResult<Channel> {
+        self.put(
+            Endpoint::ChannelPermission(channel_id.to_string(), "default".to_string()),
+            PermissionsUpdate {
+                permissions: permissions.into(),
+            },
+        )
+        .await
+    }
+
+    /// Create an invite to a server channel or group.
+    pub async fn create_invite(&self, channel_id: &str) -> SeriaResult<Invite> {
+        self.post_empty(Endpoint::ChannelInvites(channel_id.to_string()))
+            .await
+    }
+
+    /// Delete a server channel, or close a direct message or group.
+    pub async fn delete_channel(&self, channel_id: &str) -> SeriaResult {
+        self.delete(Endpoint::Channel(channel_id.to_string())).await
+    }
+
+    // Relationship-related methods
+    /// Send a friend request to the user with the given username.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn send_friend_request(&self, username: &str) -> SeriaResult<User> {
+        self.post(
+            Endpoint::RelationshipFriends(),
+            SendFriendRequest {
+                username: username.to_string(),
+            },
+        )
+        .await
+    }
+
+    /// Accept the friend request of a user.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn accept_friend_request(&self, user_id: &str) -> SeriaResult<User> {
+        self.put_empty(Endpoint::RelationshipFriend(user_id.to_string()))
             .await
     }
 
+    /// Remove a friend, or deny or cancel a friend request.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn remove_friend(&self, user_id: &str) -> SeriaResult<User> {
+        self.delete(Endpoint::RelationshipFriend(user_id.to_string()))
+            .await
+    }
+
+    /// Block a user.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn block_user(&self, user_id: &str) -> SeriaResult<User> {
+        self.put_empty(Endpoint::RelationshipBlock(user_id.to_string()))
+            .await
+    }
+
+    /// Unblock a user.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn unblock_user(&self, user_id: &str) -> SeriaResult<User> {
+        self.delete(Endpoint::RelationshipBlock(user_id.to_string()))
+            .await
+    }
+
+    /// Get the users, servers and channels shared with a user.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn fetch_mutual(&self, user_id: &str) -> SeriaResult<MutualResponse> {
+        self.get(Endpoint::RelationshipMutual(user_id.to_string()))
+            .await
+    }
+
+    // Direct message-related methods
+    /// Open a direct message channel with a user, or the saved messages channel for the bot itself.
+    pub async fn open_dm(&self, user_id: &str) -> SeriaResult<Channel> {
+        self.get(Endpoint::UserDM(user_id.to_string())).await
+    }
+
+    /// Get all direct message and group channels the bot is part of.
+    pub async fn fetch_dms(&self) -> SeriaResult<Vec<Channel>> {
+        self.get(Endpoint::UserDMs()).await
+    }
+
+    /// Send a message to a user, opening the direct message channel on first use.
+    pub async fn send_dm(
+        &self,
+        user_id: &str,
+        payload: impl Into<MessageSend>,
+    ) -> SeriaResult<Message> {
+        let cached = self.dm_channels.lock().unwrap().get(user_id).cloned();
+        let channel_id = match cached {
+            Some(channel_id) => channel_id,
+            None => {
+                let channel_id = self.open_dm(user_id).await?.id().clone();
+                self.dm_channels
+                    .lock()
+                    .unwrap()
+                    .insert(user_id.to_string(), channel_id.clone());
+                channel_id
+            }
+        };
+
+        self.send_message(&channel_id, payload).await
+    }
+
     // Message-related methods
     /// Send a message in the specified channel.
     pub async fn send_message(
@@ -159,8 +599,81 @@ impl HttpClient {
         channel_id: &str,
         payload: impl Into<MessageSend>,
     ) -> SeriaResult<Message> {
+        self.post_idempotent(
+            Endpoint::ChannelMessages(channel_id.to_string()),
+            payload.into(),
+        )
+        .await
+    }
+
+    /// Get a page of messages in the specified channel.
+    pub async fn fetch_messages(
+        &self,
+        channel_id: &str,
+        query: impl Into<MessageQuery>,
+    ) -> SeriaResult<BulkMessageResponse> {
+        self.get_query(
+            Endpoint::ChannelMessages(channel_id.to_string()),
+            &query.into(),
+        )
+        .await
+    }
+
+    /// Walk the history of the specified channel, from the newest message to the oldest.
+    ///
+    /// Pages are fetched lazily as the stream is polled.
+    pub fn message_history(
+        &self,
+        channel_id: &str,
+    ) -> impl Stream<Item = SeriaResult<Message>> + Send + 'static {
+        let http = self.clone();
+        let channel_id = channel_id.to_string();
+
+        // The cursor is `None` once the last page has been fetched.
+        stream::try_unfold(Some(None::<Id>), move |cursor| {
+            let http = http.clone();
+            let channel_id = channel_id.clone();
+
+            async move {
+                let Some(before) = cursor else {
+                    return Ok::<_, SeriaError>(None);
+                };
+
+                let mut query = MessageQuery::new()
+                    .limit(MESSAGE_PAGE_LIMIT)
+                    .sort(MessageSort::Latest);
+                if let Some(before) = before {
+                    query = query.before(before);
+                }
+
+                let messages = http
+                    .fetch_messages(&channel_id, query)
+                    .await?
+                    .into_messages();
+                let cursor = match messages.last() {
+                    Some(last) if messages.len() as u32 == MESSAGE_PAGE_LIMIT => {
+                        Some(Some(last.id.clone()))
+                    }
+                    _ => None,
+                };
+
+                Ok(Some((
+                    stream::iter(messages.into_iter().map(Ok::<_, SeriaError>)),
+                    cursor,
+                )))
+            }
+        })
+        .try_flatten()
+    }
+
+    /// Search for messages in the specified channel.
+    pub async fn search_messages(
+        &self,
+        channel_id: &str,
+        payload: impl Into<MessageSearch>,
+    ) -> SeriaResult<BulkMessageResponse> {
         self.post(
-            Endpoint::ChannelMessages(channel_id.to_string()).path(),
+            Endpoint::ChannelMessageSearch(channel_id.to_string()),
             payload.into(),
         )
         .await
@@ -174,12 +687,183 @@ impl HttpClient {
         payload: impl Into<MessageEdit>,
     ) -> SeriaResult<Message> {
         self.patch(
-            Endpoint::ChannelMessage(channel_id.to_string(), message_id.to_string()).path(),
+            Endpoint::ChannelMessage(channel_id.to_string(), message_id.to_string()),
             payload.into(),
         )
         .await
     }
 
+    /// Delete a message in the specified channel.
+    pub async fn delete_message(&self, channel_id: &str, message_id: &str) -> SeriaResult {
+        self.delete(Endpoint::ChannelMessage(
+            channel_id.to_string(),
+            message_id.to_string(),
+        ))
+        .await
+    }
+
+    /// Delete multiple messages in the specified channel, returning how many were deleted.
+    ///
+    /// Messages are deleted in batches of up to 100. Messages older than a week cannot be
+    /// deleted in bulk and are skipped.
+    pub async fn bulk_delete_messages(
+        &self,
+        channel_id: &str,
+        message_ids: impl IntoIterator<Item = impl Into<Id>>,
+    ) -> SeriaResult<usize> {
+        let cutoff = SystemTime::now() - BULK_DELETE_MAX_AGE;
+        let message_ids = message_ids
+            .into_iter()
+            .map(Into::into)
+            .filter(|id: &Id| match id_timestamp(id) {
+                Some(created_at) => created_at > cutoff,
+                None => true,
+            })
+            .collect::<Vec<_>>();
+
+        for chunk in message_ids.chunks(BULK_DELETE_LIMIT) {
+            self.delete_json(
+                Endpoint::ChannelMessageBulk(channel_id.to_string()),
+                BulkMessageDelete {
+                    ids: chunk.to_vec(),
+                },
+            )
+            .await?;
+        }
+
+        Ok(message_ids.len())
+    }
+
+    /// Delete the messages in the specified channel matching the filter, returning how many
+    /// were deleted.
+    ///
+    /// History is walked from the newest message until a message is too old to be deleted in
+    /// bulk, or older than the maximum age of the filter.
+    pub async fn purge(&self, channel_id: &str, filter: PurgeFilter) -> SeriaResult<usize> {
+        let max_age = filter.max_age.map_or(BULK_DELETE_MAX_AGE, |max_age| {
+            max_age.min(BULK_DELETE_MAX_AGE)
+        });
+        let cutoff = SystemTime::now() - max_age;
+
+        let mut history = pin!(self.message_history(channel_id));
+        let mut message_ids = Vec::new();
+
+        while let Some(message) = history.try_next().await? {
+            if message
+                .created_at()
+                .is_some_and(|created_at| created_at < cutoff)
+            {
+                break;
+            }
+
+            if filter.matches(&message) {
+                message_ids.push(message.id);
+            }
+
+            if filter.limit.is_some_and(|limit| message_ids.len() >= limit) {
+                break;
+            }
+        }
+
+        self.bulk_delete_messages(channel_id, message_ids).await
+    }
+
+    /// React to a message with an emoji, given as a unicode emoji or a custom emoji ID.
+    pub async fn add_reaction(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        emoji: &str,
+    ) -> SeriaResult {
+        self.put_empty(Endpoint::ChannelMessageReaction(
+            channel_id.to_string(),
+            message_id.to_string(),
+            emoji.to_string(),
+        ))
+        .await
+    }
+
+    /// Remove a reaction from a message.
+    ///
+    /// Removes the reaction of the given user, or of the current user when `None`.
+    pub async fn remove_reaction(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        emoji: &str,
+        user_id: Option<&str>,
+    ) -> SeriaResult {
+        self.delete_query(
+            Endpoint::ChannelMessageReaction(
+                channel_id.to_string(),
+                message_id.to_string(),
+                emoji.to_string(),
+            ),
+            &ReactionRemove {
+                user_id: user_id.map(Into::into),
+                remove_all: None,
+            },
+        )
+        .await
+    }
+
+    /// Remove the reactions of every user with the given emoji from a message.
+    pub async fn remove_all_reactions(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        emoji: &str,
+    ) -> SeriaResult {
+        self.delete_query(
+            Endpoint::ChannelMessageReaction(
+                channel_id.to_string(),
+                message_id.to_string(),
+                emoji.to_string(),
+            ),
+            &ReactionRemove {
+                user_id: None,
+                remove_all: Some(true),
+            },
+        )
+        .await
+    }
+
+    /// Remove every reaction from a message.
+    pub async fn clear_reactions(&self, channel_id: &str, message_id: &str) -> SeriaResult {
+        self.delete(Endpoint::ChannelMessageReactions(
+            channel_id.to_string(),
+            message_id.to_string(),
+        ))
+        .await
+    }
+
+    /// Pin a message in the specified channel.
+    pub async fn pin_message(&self, channel_id: &str, message_id: &str) -> SeriaResult {
+        self.post_empty(Endpoint::ChannelMessagePin(
+            channel_id.to_string(),
+            message_id.to_string(),
+        ))
+        .await
+    }
+
+    /// Unpin a message in the specified channel.
+    pub async fn unpin_message(&self, channel_id: &str, message_id: &str) -> SeriaResult {
+        self.delete(Endpoint::ChannelMessagePin(
+            channel_id.to_string(),
+            message_id.to_string(),
+        ))
+        .await
+    }
+
+    /// Get the pinned messages in the specified channel.
+    pub async fn fetch_pinned_messages(
+        &self,
+        channel_id: &str,
+    ) -> SeriaResult<BulkMessageResponse> {
+        self.search_messages(channel_id, MessageSearch::default().pinned(true))
+            .await
+    }
+
     /// Reply to a certain message in the specified channel.
     pub async fn reply_message(
         &self,
@@ -189,7 +873,7 @@ impl HttpClient {
         mention: bool,
     ) -> SeriaResult<Message> {
         let reply_intent = MessageReplyIntent {
-            id: message_id.to_string().into(),
+            id: message_id.to_string(),
             mention,
             fail_if_not_exists: true,
         };
@@ -199,4 +883,311 @@ impl HttpClient {
 
         self.send_message(channel_id, message_payload).await
     }
+
+    // Read state-related methods
+    /// Mark the specified channel as read up to a message.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn ack_message(&self, channel_id: &str, message_id: &str) -> SeriaResult {
+        self.put_empty(Endpoint::ChannelMessageAck(
+            channel_id.to_string(),
+            message_id.to_string(),
+        ))
+        .await
+    }
+
+    /// Mark all channels of a server as read.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn ack_server(&self, server_id: &str) -> SeriaResult {
+        self.put_empty(Endpoint::ServerAck(server_id.to_string()))
+            .await
+    }
+
+    /// Get the read state of every channel the current user has read.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn fetch_unreads(&self) -> SeriaResult<Vec<ChannelUnread>> {
+        self.get(Endpoint::SyncUnreads()).await
+    }
+
+    // Server-related methods
+    /// Get a server.
+    pub async fn fetch_server(&self, server_id: &str) -> SeriaResult<Server> {
+        self.get(Endpoint::Server(server_id.to_string())).await
+    }
+
+    /// Create a new server.
+    pub async fn create_server(
+        &self,
+        payload: impl Into<ServerCreate>,
+    ) -> SeriaResult<ServerCreateResponse> {
+        self.post(Endpoint::ServerCreate(), payload.into()).await
+    }
+
+    /// Edit a server.
+    pub async fn edit_server(
+        &self,
+        server_id: &str,
+        payload: impl Into<ServerEdit>,
+    ) -> SeriaResult<Server> {
+        self.patch(Endpoint::Server(server_id.to_string()), payload.into())
+            .await
+    }
+
+    /// Delete a server if owned, otherwise leave it.
+    pub async fn delete_server(&self, server_id: &str) -> SeriaResult {
+        self.delete(Endpoint::Server(server_id.to_string())).await
+    }
+
+    // Member-related methods
+    /// Get a member of a server.
+    pub async fn fetch_member(&self, server_id: &str, user_id: &str) -> SeriaResult<Member> {
+        self.get(Endpoint::ServerMember(
+            server_id.to_string(),
+            user_id.to_string(),
+        ))
+        .await
+    }
+
+    /// Get every member of a server, along with their users.
+    pub async fn fetch_members(&self, server_id: &str) -> SeriaResult<MemberList> {
+        self.get(Endpoint::ServerMembers(server_id.to_string()))
+            .await
+    }
+
+    /// Edit a member of a server.
+    pub async fn edit_member(
+        &self,
+        server_id: &str,
+        user_id: &str,
+        payload: impl Into<MemberEdit>,
+    ) -> SeriaResult<Member> {
+        self.patch(
+            Endpoint::ServerMember(server_id.to_string(), user_id.to_string()),
+            payload.into(),
+        )
+        .await
+    }
+
+    /// Kick a member from a server.
+    pub async fn kick_member(&self, server_id: &str, user_id: &str) -> SeriaResult {
+        self.delete(Endpoint::ServerMember(
+            server_id.to_string(),
+            user_id.to_string(),
+        ))
+        .await
+    }
+
+    // Ban-related methods
+    /// Ban a user from a server.
+    pub async fn ban_user(
+        &self,
+        server_id: &str,
+        user_id: &str,
+        reason: Option<&str>,
+    ) -> SeriaResult<ServerBan> {
+        self.put(
+            Endpoint::ServerBan(server_id.to_string(), user_id.to_string()),
+            BanCreate {
+                reason: reason.map(Into::into),
+            },
+        )
+        .await
+    }
+
+    /// Unban a user from a server.
+    pub async fn unban_user(&self, server_id: &str, user_id: &str) -> SeriaResult {
+        self.delete(Endpoint::ServerBan(
+            server_id.to_string(),
+            user_id.to_string(),
+        ))
+        .await
+    }
+
+    /// Get the bans of a server, along with the users they apply to.
+    pub async fn fetch_bans(&self, server_id: &str) -> SeriaResult<BanList> {
+        self.get(Endpoint::ServerBans(server_id.to_string())).await
+    }
+
+    // Role-related methods
+    /// Create a new role in a server.
+    pub async fn create_role(
+        &self,
+        server_id: &str,
+        payload: impl Into<RoleCreate>,
+    ) -> SeriaResult<RoleCreateResponse> {
+        self.post(Endpoint::ServerRoles(server_id.to_string()), payload.into())
+            .await
+    }
+
+    /// Edit a role in a server.
+    pub async fn edit_role(
+        &self,
+        server_id: &str,
+        role_id: &str,
+        payload: impl Into<RoleEdit>,
+    ) -> SeriaResult<Role> {
+        self.patch(
+            Endpoint::ServerRole(server_id.to_string(), role_id.to_string()),
+            payload.into(),
+        )
+        .await
+    }
+
+    /// Delete a role in a server.
+    pub async fn delete_role(&self, server_id: &str, role_id: &str) -> SeriaResult {
+        self.delete(Endpoint::ServerRole(
+            server_id.to_string(),
+            role_id.to_string(),
+        ))
+        .await
+    }
+
+    /// Set the permissions granted and denied by a role in a server.
+    pub async fn set_role_permissions(
+        &self,
+        server_id: &str,
+        role_id: &str,
+        permissions: impl Into<Override>,
+    ) -> SeriaResult<Server> {
+        self.put(
+            Endpoint::ServerPermission(server_id.to_string(), role_id.to_string()),
+            PermissionsUpdate {
+                permissions: permissions.into(),
+            },
+        )
+        .await
+    }
+
+    /// Set the permissions granted to everyone in a server.
+    pub async fn set_default_permissions(
+        &self,
+        server_id: &str,
+        permissions: Permission,
+    ) -> SeriaResult<Server> {
+        self.put(
+            Endpoint::ServerPermission(server_id.to_string(), "default".to_string()),
+            PermissionsUpdate { permissions },
+        )
+        .await
+    }
+
+    // Invite-related methods
+    /// Get the invites of a server.
+    pub async fn fetch_server_invites(&self, server_id: &str) -> SeriaResult<Vec<Invite>> {
+        self.get(Endpoint::ServerInvites(server_id.to_string()))
+            .await
+    }
+
+    /// Get the public information of an invite.
+    pub async fn fetch_invite(&self, code: &str) -> SeriaResult<InviteInfo> {
+        self.get(Endpoint::Invite(code.to_string())).await
+    }
+
+    /// Join the server or group an invite points to.
+    pub async fn join_invite(&self, code: &str) -> SeriaResult<InviteJoin> {
+        self.post_empty(Endpoint::Invite(code.to_string())).await
+    }
+
+    /// Delete an invite.
+    pub async fn delete_invite(&self, code: &str) -> SeriaResult {
+        self.delete(Endpoint::Invite(code.to_string())).await
+    }
+
+    // Webhook-related methods
+    /// Create a webhook in the specified channel.
+    pub async fn create_webhook(
+        &self,
+        channel_id: &str,
+        payload: impl Into<WebhookCreate>,
+    ) -> SeriaResult<Webhook> {
+        self.post(
+            Endpoint::ChannelWebhooks(channel_id.to_string()),
+            payload.into(),
+        )
+        .await
+    }
+
+    /// Get the webhooks of the specified channel.
+    pub async fn fetch_channel_webhooks(&self, channel_id: &str) -> SeriaResult<Vec<Webhook>> {
+        self.get(Endpoint::ChannelWebhooks(channel_id.to_string()))
+            .await
+    }
+
+    /// Get a webhook.
+    pub async fn fetch_webhook(&self, webhook_id: &str) -> SeriaResult<Webhook> {
+        self.get(Endpoint::Webhook(webhook_id.to_string())).await
+    }
+
+    /// Get a webhook using its token.
+    pub async fn fetch_webhook_with_token(
+        &self,
+        webhook_id: &str,
+        token: &str,
+    ) -> SeriaResult<Webhook> {
+        self.get(Endpoint::WebhookToken(
+            webhook_id.to_string(),
+            token.to_string(),
+        ))
+        .await
+    }
+
+    /// Edit a webhook.
+    pub async fn edit_webhook(
+        &self,
+        webhook_id: &str,
+        payload: impl Into<WebhookEdit>,
+    ) -> SeriaResult<Webhook> {
+        self.patch(Endpoint::Webhook(webhook_id.to_string()), payload.into())
+            .await
+    }
+
+    /// Delete a webhook.
+    pub async fn delete_webhook(&self, webhook_id: &str) -> SeriaResult {
+        self.delete(Endpoint::Webhook(webhook_id.to_string())).await
+    }
+
+    /// Send a message through a webhook using its token.
+    pub async fn execute_webhook(
+        &self,
+        webhook_id: &str,
+        token: &str,
+        payload: impl Into<MessageSend>,
+    ) -> SeriaResult<Message> {
+        self.post_idempotent(
+            Endpoint::WebhookToken(webhook_id.to_string(), token.to_string()),
+            payload.into(),
+        )
+        .await
+    }
+
+    // Emoji-related methods
+    /// Get a custom emoji.
+    pub async fn fetch_emoji(&self, emoji_id: &str) -> SeriaResult<Emoji> {
+        self.get(Endpoint::Emoji(emoji_id.to_string())).await
+    }
+
+    /// Get the custom emojis of a server.
+    pub async fn fetch_server_emojis(&self, server_id: &str) -> SeriaResult<Vec<Emoji>> {
+        self.get(Endpoint::ServerEmojis(server_id.to_string()))
+            .await
+    }
+
+    /// Create a custom emoji from a file uploaded with the `emojis` tag.
+    pub async fn create_emoji(&self, file_id: &str, payload: EmojiCreate) -> SeriaResult<Emoji> {
+        self.put(Endpoint::Emoji(file_id.to_string()), payload)
+            .await
+    }
+
+    /// Delete a custom emoji.
+    pub async fn delete_emoji(&self, emoji_id: &str) -> SeriaResult {
+        self.delete(Endpoint::Emoji(emoji_id.to_string())).await
+    }
+
+    // Safety-related methods
+    /// Report a message, server or user to the platform moderation team.
+    pub async fn report_content(&self, payload: impl Into<ContentReport>) -> SeriaResult {
+        self.post(Endpoint::UserSafety(), payload.into()).await
+    }
 }
@@ -1,9 +1,17 @@
+use {
+    reqwest::{Error as ReqwestError, Method, StatusCode},
+    std::time::Duration,
+};
+
 use crate::error::SeriaError;
 
 #[derive(Clone, Debug)]
 pub struct HttpConfig {
     pub token: String,
+    pub token_kind: TokenKind,
     pub api_url: String,
+    pub autumn_url: String,
+    pub retry: RetryPolicy,
 }
 
 impl HttpConfig {
@@ -14,7 +22,142 @@ impl HttpConfig {
         }
         Ok(HttpConfig {
             token,
+            token_kind: TokenKind::default(),
             api_url: "https://api.revolt.chat".into(),
+            autumn_url: "https://autumn.revolt.chat".into(),
+            retry: RetryPolicy::default(),
+        })
+    }
+
+    /// Create a configuration authenticated as a user account with a session token.
+    ///
+    /// Routes which the API reserves to users, such as relationships, read states and bot
+    /// management, require this instead of a bot token.
+    pub fn session(token: impl Into<String>) -> Result<Self, SeriaError> {
+        Ok(HttpConfig {
+            token_kind: TokenKind::Session,
+            ..Self::new(token)?
         })
     }
+
+    /// Create a configuration without a token, for requests authenticated by other means
+    /// such as webhook tokens.
+    pub fn anonymous() -> Self {
+        HttpConfig {
+            token: String::new(),
+            token_kind: TokenKind::default(),
+            api_url: "https://api.revolt.chat".into(),
+            autumn_url: "https://autumn.revolt.chat".into(),
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Set the URL of the Autumn file server uploads are sent to.
+    pub fn autumn_url(mut self, url: impl Into<String>) -> Self {
+        self.autumn_url = url.into();
+        self
+    }
+
+    /// Set the policy used to retry failed requests.
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+}
+
+/// The kind of token a client authenticates with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TokenKind {
+    /// A bot token, sent as `X-Bot-Token`.
+    #[default]
+    Bot,
+    /// The session token of a user account, sent as `X-Session-Token`.
+    Session,
+}
+
+impl TokenKind {
+    /// The header the token is sent in.
+    pub fn header(&self) -> &'static str {
+        match self {
+            TokenKind::Bot => "X-Bot-Token",
+            TokenKind::Session => "X-Session-Token",
+        }
+    }
+}
+
+/// Describes which failed requests are retried and how long to wait between attempts.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled on every further attempt.
+    pub base_delay: Duration,
+    /// The upper bound of the delay between two attempts.
+    pub max_delay: Duration,
+    /// The request methods which are retried.
+    ///
+    /// Requests carrying an `Idempotency-Key` header are retried whatever their method,
+    /// since the API discards duplicates of them.
+    pub methods: Vec<Method>,
+    /// The response status codes which are retried.
+    pub statuses: Vec<StatusCode>,
+    /// Whether requests which failed to connect are retried.
+    pub retry_connect: bool,
+    /// Whether requests which timed out are retried.
+    pub retry_timeout: bool,
+}
+
+impl RetryPolicy {
+    /// A policy which never retries.
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Whether a request with this method may be retried.
+    pub fn is_retryable_method(&self, method: &Method) -> bool {
+        self.methods.contains(method)
+    }
+
+    /// Whether a response with this status should be retried.
+    pub fn is_retryable_status(&self, status: StatusCode) -> bool {
+        self.statuses.contains(&status)
+    }
+
+    /// Whether a request which failed with this error should be retried.
+    pub fn is_retryable_error(&self, error: &ReqwestError) -> bool {
+        (self.retry_connect && error.is_connect()) || (self.retry_timeout && error.is_timeout())
+    }
+
+    /// The delay to wait after the given failed attempt, starting at 1.
+    ///
+    /// The delay grows exponentially and is randomised between half and all of it, so
+    /// that clients failing together do not retry together.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+
+        delay / 2 + delay.mul_f64(fastrand::f64() / 2.0)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            methods: vec![Method::GET, Method::PUT, Method::PATCH, Method::DELETE],
+            statuses: vec![
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_connect: true,
+            retry_timeout: true,
+        }
+    }
 }
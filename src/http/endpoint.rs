@@ -1,3 +1,5 @@
+use reqwest::Method;
+
 #[derive(Debug, Clone)]
 pub enum Endpoint {
     // Bot-related
@@ -42,6 +44,7 @@ pub enum Endpoint {
     ServerBans(String),
     ServerChannels(String),
     ServerCreate(),
+    ServerEmojis(String),
     ServerInvites(String),
     ServerMember(String, String),
     ServerMemberExperimentalQuery(String),
@@ -50,6 +53,9 @@ pub enum Endpoint {
     ServerRole(String, String),
     ServerRoles(String),
 
+    // Sync-related
+    SyncUnreads(),
+
     // User-related
     User(String),
     UserDM(String),
@@ -59,6 +65,10 @@ pub enum Endpoint {
     UserProfile(String),
     UserSafety(),
     UserUsername(String),
+
+    // Webhook-related
+    Webhook(String),
+    WebhookToken(String, String),
 }
 
 impl Endpoint {
@@ -67,12 +77,12 @@ impl Endpoint {
         match self {
             // Bot-related
             Endpoint::Bot(bot_id) => format!("/bots/{}", bot_id),
-            Endpoint::BotCreate() => format!("/bots/create"),
+            Endpoint::BotCreate() => "/bots/create".to_string(),
             Endpoint::BotInvite(bot_id) => format!("/bots/{}/invite", bot_id),
 
             // Channel-related
             Endpoint::Channel(channel_id) => format!("/channels/{}", channel_id),
-            Endpoint::ChannelCreate() => format!("/channels/create"),
+            Endpoint::ChannelCreate() => "/channels/create".to_string(),
             Endpoint::ChannelInvites(channel_id) => format!("/channels/{}/invites", channel_id),
             Endpoint::ChannelJoinCall(channel_id) => format!("/channels/{}/join_call", channel_id),
             Endpoint::ChannelMembers(channel_id) => {
@@ -91,7 +101,10 @@ impl Endpoint {
                 format!("/channels/{}/messages/{}/pin", channel_id, message_id)
             }
             Endpoint::ChannelMessageReaction(channel_id, message_id, emoji_id) => {
-                format!("/channels/{}/messages/{}/reactions/{}", channel_id, message_id, emoji_id)
+                format!(
+                    "/channels/{}/messages/{}/reactions/{}",
+                    channel_id, message_id, emoji_id
+                )
             }
             Endpoint::ChannelMessageReactions(channel_id, message_id) => {
                 format!("/channels/{}/messages/{}/reactions", channel_id, message_id)
@@ -117,7 +130,7 @@ impl Endpoint {
             // Relationship-related
             Endpoint::RelationshipBlock(user_id) => format!("/users/{}/block", user_id),
             Endpoint::RelationshipFriend(user_id) => format!("/users/{}/friend", user_id),
-            Endpoint::RelationshipFriends() => format!("/users/friend"),
+            Endpoint::RelationshipFriends() => "/users/friend".to_string(),
             Endpoint::RelationshipMutual(user_id) => format!("/users/{}/mutual", user_id),
 
             // Server-related
@@ -128,30 +141,192 @@ impl Endpoint {
             }
             Endpoint::ServerBans(server_id) => format!("/servers/{}/bans", server_id),
             Endpoint::ServerChannels(server_id) => format!("/servers/{}/channels", server_id),
-            Endpoint::ServerCreate() => format!("/servers/create"),
+            Endpoint::ServerCreate() => "/servers/create".to_string(),
+            Endpoint::ServerEmojis(server_id) => format!("/servers/{}/emojis", server_id),
             Endpoint::ServerInvites(server_id) => format!("/servers/{}/invites", server_id),
             Endpoint::ServerMember(server_id, member_id) => {
                 format!("/servers/{}/members/{}", server_id, member_id)
             }
-            Endpoint::ServerMemberExperimentalQuery(server_id) => format!("/servers/{}/members_experimental_query", server_id),
+            Endpoint::ServerMemberExperimentalQuery(server_id) => {
+                format!("/servers/{}/members_experimental_query", server_id)
+            }
             Endpoint::ServerMembers(server_id) => format!("/servers/{}/members", server_id),
             Endpoint::ServerPermission(server_id, role_id) => {
-                format!("/server/{}/permissions/{}", server_id, role_id)
+                format!("/servers/{}/permissions/{}", server_id, role_id)
             }
             Endpoint::ServerRole(server_id, role_id) => {
                 format!("/servers/{}/roles/{}", server_id, role_id)
             }
-            Endpoint::ServerRoles(server_id) => format!("/server/{}/roles", server_id),
+            Endpoint::ServerRoles(server_id) => format!("/servers/{}/roles", server_id),
+
+            // Sync-related
+            Endpoint::SyncUnreads() => "/sync/unreads".to_string(),
 
             // User-related
             Endpoint::User(user_id) => format!("/users/{}", user_id),
             Endpoint::UserDM(user_id) => format!("/users/{}/dm", user_id),
-            Endpoint::UserDMs() => format!("/users/dms"),
+            Endpoint::UserDMs() => "/users/dms".to_string(),
             Endpoint::UserDefaultAvatar(user_id) => format!("/users/{}/default_avatar", user_id),
             Endpoint::UserFlags(user_id) => format!("/users/{}/flags", user_id),
             Endpoint::UserProfile(user_id) => format!("/users/{}/profile", user_id),
-            Endpoint::UserSafety() => format!("/safety/report"),
+            Endpoint::UserSafety() => "/safety/report".to_string(),
             Endpoint::UserUsername(user_id) => format!("/users/{}/username", user_id),
+
+            // Webhook-related
+            Endpoint::Webhook(webhook_id) => format!("/webhooks/{}", webhook_id),
+            Endpoint::WebhookToken(webhook_id, token) => {
+                format!("/webhooks/{}/{}", webhook_id, token)
+            }
         }
     }
+
+    /// Returns the rate limit bucket a request to this endpoint is counted against.
+    ///
+    /// This mirrors how the API groups routes, keyed by the targeted resource where
+    /// the API tracks buckets per resource.
+    pub fn bucket(&self, method: &Method) -> String {
+        match self {
+            // Bot-related
+            Endpoint::Bot(_) | Endpoint::BotCreate() | Endpoint::BotInvite(_) => "bots".to_string(),
+
+            // Channel-related
+            Endpoint::ChannelCreate() => "channels:create".to_string(),
+            Endpoint::ChannelMessage(channel_id, _)
+            | Endpoint::ChannelMessageBulk(channel_id)
+            | Endpoint::ChannelMessagePin(channel_id, _)
+            | Endpoint::ChannelMessageReaction(channel_id, _, _)
+            | Endpoint::ChannelMessageReactions(channel_id, _)
+            | Endpoint::ChannelMessages(channel_id)
+                if method == Method::POST =>
+            {
+                format!("messaging:{}", channel_id)
+            }
+            Endpoint::Channel(channel_id)
+            | Endpoint::ChannelInvites(channel_id)
+            | Endpoint::ChannelJoinCall(channel_id)
+            | Endpoint::ChannelMembers(channel_id)
+            | Endpoint::ChannelMessage(channel_id, _)
+            | Endpoint::ChannelMessageAck(channel_id, _)
+            | Endpoint::ChannelMessageBulk(channel_id)
+            | Endpoint::ChannelMessagePin(channel_id, _)
+            | Endpoint::ChannelMessageReaction(channel_id, _, _)
+            | Endpoint::ChannelMessageReactions(channel_id, _)
+            | Endpoint::ChannelMessageSearch(channel_id)
+            | Endpoint::ChannelMessages(channel_id)
+            | Endpoint::ChannelPermission(channel_id, _)
+            | Endpoint::ChannelRecipient(channel_id, _)
+            | Endpoint::ChannelWebhooks(channel_id) => format!("channels:{}", channel_id),
+
+            // Emoji-related
+            Endpoint::Emoji(_) => "any".to_string(),
+
+            // Invite-related
+            Endpoint::Invite(_) => "any".to_string(),
+
+            // Relationship-related
+            Endpoint::RelationshipBlock(_)
+            | Endpoint::RelationshipFriend(_)
+            | Endpoint::RelationshipFriends()
+            | Endpoint::RelationshipMutual(_) => "users".to_string(),
+
+            // Server-related
+            Endpoint::ServerCreate() => "servers:create".to_string(),
+            Endpoint::Server(server_id)
+            | Endpoint::ServerAck(server_id)
+            | Endpoint::ServerBan(server_id, _)
+            | Endpoint::ServerBans(server_id)
+            | Endpoint::ServerChannels(server_id)
+            | Endpoint::ServerEmojis(server_id)
+            | Endpoint::ServerInvites(server_id)
+            | Endpoint::ServerMember(server_id, _)
+            | Endpoint::ServerMemberExperimentalQuery(server_id)
+            | Endpoint::ServerMembers(server_id)
+            | Endpoint::ServerPermission(server_id, _)
+            | Endpoint::ServerRole(server_id, _)
+            | Endpoint::ServerRoles(server_id) => format!("servers:{}", server_id),
+
+            // Sync-related
+            Endpoint::SyncUnreads() => "any".to_string(),
+
+            // User-related
+            Endpoint::User(user_id) if method == Method::PATCH => format!("user_edit:{}", user_id),
+            Endpoint::UserSafety() => "safety_report".to_string(),
+            Endpoint::User(_)
+            | Endpoint::UserDM(_)
+            | Endpoint::UserDMs()
+            | Endpoint::UserDefaultAvatar(_)
+            | Endpoint::UserFlags(_)
+            | Endpoint::UserProfile(_)
+            | Endpoint::UserUsername(_) => "users".to_string(),
+
+            // Webhook-related
+            Endpoint::Webhook(_) | Endpoint::WebhookToken(_, _) => "any".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id() -> String {
+        "01H".to_string()
+    }
+
+    #[test]
+    fn message_sends_use_the_messaging_bucket() {
+        let endpoint = Endpoint::ChannelMessages(id());
+
+        assert_eq!(endpoint.bucket(&Method::POST), "messaging:01H");
+        assert_eq!(endpoint.bucket(&Method::GET), "channels:01H");
+    }
+
+    #[test]
+    fn message_routes_share_the_channel_bucket() {
+        assert_eq!(
+            Endpoint::ChannelMessage(id(), "m".into()).bucket(&Method::PATCH),
+            "channels:01H"
+        );
+        assert_eq!(
+            Endpoint::ChannelMessageReaction(id(), "m".into(), "e".into()).bucket(&Method::PUT),
+            "channels:01H"
+        );
+        assert_eq!(
+            Endpoint::ChannelMessageAck(id(), "m".into()).bucket(&Method::PUT),
+            "channels:01H"
+        );
+    }
+
+    #[test]
+    fn user_edits_use_their_own_bucket() {
+        let endpoint = Endpoint::User("@me".into());
+
+        assert_eq!(endpoint.bucket(&Method::PATCH), "user_edit:@me");
+        assert_eq!(endpoint.bucket(&Method::GET), "users");
+        assert_eq!(Endpoint::UserDM(id()).bucket(&Method::GET), "users");
+    }
+
+    #[test]
+    fn server_routes_are_keyed_by_server() {
+        assert_eq!(Endpoint::Server(id()).bucket(&Method::GET), "servers:01H");
+        assert_eq!(
+            Endpoint::ServerMember(id(), "u".into()).bucket(&Method::DELETE),
+            "servers:01H"
+        );
+        assert_eq!(
+            Endpoint::ServerCreate().bucket(&Method::POST),
+            "servers:create"
+        );
+    }
+
+    #[test]
+    fn other_routes_use_shared_buckets() {
+        assert_eq!(Endpoint::BotCreate().bucket(&Method::POST), "bots");
+        assert_eq!(
+            Endpoint::UserSafety().bucket(&Method::POST),
+            "safety_report"
+        );
+        assert_eq!(Endpoint::Invite(id()).bucket(&Method::GET), "any");
+        assert_eq!(Endpoint::SyncUnreads().bucket(&Method::GET), "any");
+    }
 }
@@ -1,8 +1,13 @@
 pub use {
+    autumn::*,
     client::*,
     config::*,
+    webhook::*,
 };
 
+mod autumn;
 mod client;
 mod config;
+mod ratelimit;
+mod webhook;
 pub mod endpoint;
@@ -0,0 +1,242 @@
+//! Client-side bookkeeping of Revolt's rate limit buckets.
+//!
+//! Every response carries the state of the bucket it was counted against. Requests that
+//! would exceed an exhausted bucket are held back until it resets instead of being sent.
+
+use {
+    reqwest::header::HeaderMap,
+    std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+        time::Duration,
+    },
+    tokio::{
+        sync::Mutex as AsyncMutex,
+        time::{sleep_until, Instant},
+    },
+};
+
+/// The state of a single bucket, as last reported by the API.
+#[derive(Clone, Debug)]
+struct Bucket {
+    /// The bucket name reported in `X-RateLimit-Bucket`.
+    name: Option<String>,
+    /// The maximum number of requests per window.
+    limit: u32,
+    /// The number of requests left in the current window.
+    remaining: u32,
+    /// When the current window ends.
+    reset_at: Instant,
+}
+
+/// A bucket along with the requests waiting on it.
+///
+/// Waiting requests queue on `queue`, so that `state` is only ever locked briefly and
+/// responses can update it while a request sleeps until the reset.
+#[derive(Debug, Default)]
+struct SharedBucket {
+    queue: AsyncMutex<()>,
+    state: Mutex<Option<Bucket>>,
+}
+
+/// Tracks rate limit buckets and queues requests which would exceed them.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<HashMap<String, Arc<SharedBucket>>>>,
+}
+
+impl RateLimiter {
+    fn bucket(&self, key: &str) -> Arc<SharedBucket> {
+        self.buckets
+            .lock()
+            .unwrap()
+            .entry(key.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Wait until the bucket has room for another request and reserve it.
+    ///
+    /// Requests waiting on the same bucket are released in the order they arrived.
+    pub async fn acquire(&self, key: &str) {
+        let bucket = self.bucket(key);
+        let _turn = bucket.queue.lock().await;
+
+        loop {
+            let reset_at = {
+                let mut state = bucket.state.lock().unwrap();
+                let Some(bucket) = state.as_mut() else {
+                    return;
+                };
+
+                if bucket.remaining == 0 && Instant::now() >= bucket.reset_at {
+                    bucket.remaining = bucket.limit.max(1);
+                }
+
+                if bucket.remaining > 0 {
+                    bucket.remaining -= 1;
+                    return;
+                }
+
+                tracing::debug!(
+                    "Bucket {} ({:?}) exhausted, waiting {:?}",
+                    key,
+                    bucket.name,
+                    bucket.reset_at - Instant::now()
+                );
+                bucket.reset_at
+            };
+
+            sleep_until(reset_at).await;
+        }
+    }
+
+    /// Update the bucket from the rate limit headers of a response.
+    pub fn update(&self, key: &str, headers: &HeaderMap) {
+        let now = Instant::now();
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+        let (Some(limit), Some(remaining), Some(reset_after)) = (
+            header("X-RateLimit-Limit").and_then(|value| value.parse().ok()),
+            header("X-RateLimit-Remaining").and_then(|value| value.parse().ok()),
+            header("X-RateLimit-Reset-After").and_then(|value| value.parse().ok()),
+        ) else {
+            return;
+        };
+
+        *self.bucket(key).state.lock().unwrap() = Some(Bucket {
+            name: header("X-RateLimit-Bucket").map(Into::into),
+            limit,
+            remaining,
+            reset_at: now + Duration::from_millis(reset_after),
+        });
+    }
+
+    /// Mark the bucket as exhausted for the given duration, after being rate limited.
+    pub fn exhaust(&self, key: &str, retry_after: Duration) {
+        let reset_at = Instant::now() + retry_after;
+        let bucket = self.bucket(key);
+        let mut state = bucket.state.lock().unwrap();
+
+        match state.as_mut() {
+            Some(bucket) => {
+                bucket.remaining = 0;
+                bucket.reset_at = bucket.reset_at.max(reset_at);
+            }
+            None => {
+                *state = Some(Bucket {
+                    name: None,
+                    limit: 1,
+                    remaining: 0,
+                    reset_at,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, reqwest::header::HeaderValue, tokio::time::advance};
+
+    fn headers(limit: u32, remaining: u32, reset_after: u64) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", HeaderValue::from(limit));
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from(remaining));
+        headers.insert("X-RateLimit-Reset-After", HeaderValue::from(reset_after));
+        headers
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_without_state_does_not_wait() {
+        let limiter = RateLimiter::default();
+        let start = Instant::now();
+
+        limiter.acquire("bucket").await;
+        limiter.acquire("bucket").await;
+
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_waits_for_reset_once_exhausted() {
+        let limiter = RateLimiter::default();
+        let start = Instant::now();
+        limiter.update("bucket", &headers(2, 1, 1000));
+
+        limiter.acquire("bucket").await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire("bucket").await;
+        assert_eq!(start.elapsed(), Duration::from_millis(1000));
+
+        // The window was refilled to the limit, one request of which was just taken.
+        limiter.acquire("bucket").await;
+        assert_eq!(start.elapsed(), Duration::from_millis(1000));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn update_without_headers_is_ignored() {
+        let limiter = RateLimiter::default();
+        let start = Instant::now();
+        limiter.update("bucket", &HeaderMap::new());
+
+        limiter.acquire("bucket").await;
+
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn exhaust_without_state_waits_retry_after() {
+        let limiter = RateLimiter::default();
+        let start = Instant::now();
+        limiter.exhaust("bucket", Duration::from_millis(300));
+
+        limiter.acquire("bucket").await;
+
+        assert_eq!(start.elapsed(), Duration::from_millis(300));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn exhaust_keeps_the_later_reset() {
+        let limiter = RateLimiter::default();
+        let start = Instant::now();
+        limiter.update("bucket", &headers(5, 5, 2000));
+        limiter.exhaust("bucket", Duration::from_millis(500));
+
+        limiter.acquire("bucket").await;
+
+        assert_eq!(start.elapsed(), Duration::from_millis(2000));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn waiting_request_sees_updates_made_while_it_sleeps() {
+        let limiter = RateLimiter::default();
+        let start = Instant::now();
+        limiter.update("bucket", &headers(1, 0, 1000));
+
+        let waiter = tokio::spawn({
+            let limiter = limiter.clone();
+            async move {
+                limiter.acquire("bucket").await;
+                Instant::now()
+            }
+        });
+
+        advance(Duration::from_millis(500)).await;
+        limiter.update("bucket", &headers(1, 0, 1000));
+
+        assert_eq!(waiter.await.unwrap() - start, Duration::from_millis(1500));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn buckets_are_independent() {
+        let limiter = RateLimiter::default();
+        let start = Instant::now();
+        limiter.exhaust("exhausted", Duration::from_secs(10));
+
+        limiter.acquire("other").await;
+
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    error::SeriaResult,
+    http::{HttpClient, HttpConfig},
+    models::{Id, Message, MessageSend, Webhook},
+};
+
+/// A client for a single webhook, authenticated by the webhook token alone.
+///
+/// It needs neither a bot token nor a gateway connection.
+#[derive(Clone, Debug)]
+pub struct WebhookClient {
+    http: HttpClient,
+    id: Id,
+    token: String,
+}
+
+impl WebhookClient {
+    /// Create a client for the webhook with the given ID and token.
+    pub fn new(id: impl Into<Id>, token: impl Into<String>) -> Self {
+        Self::with_config(id, token, HttpConfig::anonymous())
+    }
+
+    /// Create a client for the webhook, sending requests as configured.
+    pub fn with_config(id: impl Into<Id>, token: impl Into<String>, config: HttpConfig) -> Self {
+        WebhookClient {
+            http: HttpClient::new(config),
+            id: id.into(),
+            token: token.into(),
+        }
+    }
+
+    /// Get the webhook.
+    pub async fn fetch(&self) -> SeriaResult<Webhook> {
+        self.http
+            .fetch_webhook_with_token(&self.id, &self.token)
+            .await
+    }
+
+    /// Send a message through the webhook.
+    pub async fn execute(&self, payload: impl Into<MessageSend>) -> SeriaResult<Message> {
+        self.http
+            .execute_webhook(&self.id, &self.token, payload)
+            .await
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::models::Id;
+use crate::{http::HttpConfig, models::Id};
 
 /// Represents a stored media object, such as an avatar, icon, or message file.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -20,6 +20,24 @@ pub struct Attachment {
     pub tag: AttachmentTag,
 }
 
+impl Attachment {
+    /// The URL the file is served from by the configured Autumn file server.
+    pub fn url(&self, config: &HttpConfig) -> String {
+        format!(
+            "{}/{}/{}",
+            config.autumn_url.trim_end_matches('/'),
+            self.tag.as_str(),
+            self.id
+        )
+    }
+
+    /// The URL of the image, scaled down by Autumn so that its longest side is at most
+    /// `max_side` pixels.
+    pub fn resized_url(&self, config: &HttpConfig, max_side: u32) -> String {
+        format!("{}?max_side={}", self.url(config), max_side)
+    }
+}
+
 /// Logical category assigned to an attachment.
 #[derive(Debug, Deserialize, Clone, Copy, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -29,6 +47,33 @@ pub enum AttachmentTag {
     Banners,
     Backgrounds,
     Icons,
+    Emojis,
+}
+
+impl AttachmentTag {
+    /// The name of the tag, as used in the Autumn upload route.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AttachmentTag::Attachments => "attachments",
+            AttachmentTag::Avatars => "avatars",
+            AttachmentTag::Banners => "banners",
+            AttachmentTag::Backgrounds => "backgrounds",
+            AttachmentTag::Icons => "icons",
+            AttachmentTag::Emojis => "emojis",
+        }
+    }
+
+    /// The maximum size in bytes of a file uploaded with this tag.
+    pub fn max_size(&self) -> u64 {
+        match self {
+            AttachmentTag::Attachments => 20_000_000,
+            AttachmentTag::Avatars => 4_000_000,
+            AttachmentTag::Banners => 6_000_000,
+            AttachmentTag::Backgrounds => 6_000_000,
+            AttachmentTag::Icons => 2_500_000,
+            AttachmentTag::Emojis => 500_000,
+        }
+    }
 }
 
 /// Type-specific metadata associated with an attachment.
@@ -1,6 +1,172 @@
-use serde::Deserialize;
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
 
-use crate::models::Id;
+use crate::{
+    http::HttpClient,
+    models::{Id, User},
+    SeriaResult,
+};
+
+/// Represents a bot owned by the current user.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Bot {
+    /// The ID of the bot.
+    #[serde(rename = "_id")]
+    pub id: Id,
+    /// The ID of the user owning the bot.
+    pub owner: Id,
+    /// The token used to authenticate as the bot.
+    pub token: String,
+    /// Whether the bot can be invited by anyone.
+    pub public: bool,
+    /// Whether the bot shares analytics with the platform.
+    #[serde(default)]
+    pub analytics: bool,
+    /// Whether the bot is listed in the discovery.
+    #[serde(default)]
+    pub discoverable: bool,
+    /// The URL interaction events are sent to.
+    pub interactions_url: Option<String>,
+    /// The URL of the terms of service of the bot.
+    pub terms_of_service_url: Option<String>,
+    /// The URL of the privacy policy of the bot.
+    pub privacy_policy_url: Option<String>,
+    /// The flags of the bot.
+    #[serde(default)]
+    pub flags: BotFlags,
+}
+
+impl Bot {
+    /// Edit this bot.
+    pub async fn edit(&self, http: &HttpClient, payload: impl Into<BotEdit>) -> SeriaResult<Self> {
+        http.edit_bot(&self.id, payload).await
+    }
+
+    /// Delete this bot.
+    pub async fn delete(&self, http: &HttpClient) -> SeriaResult {
+        http.delete_bot(&self.id).await
+    }
+
+    /// Invite this bot to a server or group.
+    pub async fn invite(
+        &self,
+        http: &HttpClient,
+        destination: BotInviteDestination,
+    ) -> SeriaResult {
+        http.invite_bot(&self.id, destination).await
+    }
+}
+
+bitflags! {
+    #[derive(Clone, Debug, PartialEq, Default)]
+    pub struct BotFlags: u32 {
+        const Verified = 1;
+        const Official = 2;
+    }
+}
+
+bitflags_serde!(BotFlags: u32);
+
+/// Represents a bot along with its user.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BotResponse {
+    pub bot: Bot,
+    pub user: User,
+}
+
+/// Represents the bots owned by the current user along with their users.
+#[derive(Clone, Debug, Deserialize)]
+pub struct OwnedBots {
+    pub bots: Vec<Bot>,
+    pub users: Vec<User>,
+}
+
+/// Represents the fields that can be removed from a bot object.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum BotFields {
+    /// Resets the token of the bot.
+    Token,
+    #[serde(rename = "InteractionsURL")]
+    InteractionsUrl,
+}
+
+/// Represents a request to create a new bot.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BotCreate {
+    pub name: String,
+}
+
+impl<T: Into<String>> From<T> for BotCreate {
+    fn from(name: T) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+/// Represents a request to edit an existing bot.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BotEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analytics: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interactions_url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<BotFields>,
+}
+
+impl BotEdit {
+    /// Create a new empty edit request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The new username of the bot.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Whether the bot can be invited by anyone.
+    pub fn public(mut self, public: bool) -> Self {
+        self.public = Some(public);
+        self
+    }
+
+    /// Whether the bot shares analytics with the platform.
+    pub fn analytics(mut self, analytics: bool) -> Self {
+        self.analytics = Some(analytics);
+        self
+    }
+
+    /// The URL interaction events are sent to.
+    pub fn interactions_url(mut self, url: impl Into<String>) -> Self {
+        self.interactions_url = Some(url.into());
+        self
+    }
+
+    /// Generate a new token for the bot, invalidating the current one.
+    pub fn reset_token(mut self) -> Self {
+        self.remove.push(BotFields::Token);
+        self
+    }
+
+    /// Remove a field from the bot.
+    pub fn remove(mut self, field: BotFields) -> Self {
+        self.remove.push(field);
+        self
+    }
+}
+
+/// Represents where a bot is invited to.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum BotInviteDestination {
+    Server { server: Id },
+    Group { group: Id },
+}
 
 /// Represents a public bot.
 #[derive(Clone, Debug, Deserialize)]
@@ -14,5 +180,4 @@ pub struct PublicBot {
     pub avatar: Option<String>,
     /// The description of the bot.
     pub description: Option<String>,
-
 }
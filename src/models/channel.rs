@@ -1,11 +1,83 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-use crate::models::Id;
+use crate::{
+    http::HttpClient,
+    models::{
+        attachment::Attachment,
+        permission::{OverrideField, Permission},
+        Id,
+    },
+    SeriaResult,
+};
 
-/// Represents a channel in a server.
+/// Represents a channel on the Revolt platform.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "channel_type")]
 pub enum Channel {
+    SavedMessages(SavedMessagesChannel),
     DirectMessage(DirectMessageChannel),
+    Group(GroupChannel),
+    TextChannel(TextChannel),
+    VoiceChannel(VoiceChannel),
+}
+
+impl Channel {
+    /// The ID of the channel.
+    pub fn id(&self) -> &Id {
+        match self {
+            Channel::SavedMessages(channel) => &channel.id,
+            Channel::DirectMessage(channel) => &channel.id,
+            Channel::Group(channel) => &channel.id,
+            Channel::TextChannel(channel) => &channel.id,
+            Channel::VoiceChannel(channel) => &channel.id,
+        }
+    }
+
+    /// The ID of the server the channel belongs to, if any.
+    pub fn server(&self) -> Option<&Id> {
+        match self {
+            Channel::TextChannel(channel) => Some(&channel.server),
+            Channel::VoiceChannel(channel) => Some(&channel.server),
+            _ => None,
+        }
+    }
+
+    /// Edit this channel.
+    pub async fn edit(
+        &self,
+        http: &HttpClient,
+        payload: impl Into<ChannelUpdate>,
+    ) -> SeriaResult<Self> {
+        http.edit_channel(self.id(), payload).await
+    }
+
+    /// Delete this channel, or close it if it is a direct message or group.
+    pub async fn delete(&self, http: &HttpClient) -> SeriaResult {
+        http.delete_channel(self.id()).await
+    }
+}
+
+/// Represents the read state of a channel for the current user.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ChannelUnread {
+    /// The channel and user the read state belongs to.
+    #[serde(rename = "_id")]
+    pub id: ChannelCompositeKey,
+    /// The ID of the last message read in the channel.
+    pub last_id: Option<Id>,
+    /// The IDs of the unread messages mentioning the user.
+    #[serde(default)]
+    pub mentions: Vec<Id>,
+}
+
+/// Represents the compound key identifying a read state.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ChannelCompositeKey {
+    /// The ID of the channel.
+    pub channel: Id,
+    /// The ID of the user.
+    pub user: Id,
 }
 
 /// Represents the fields that can be included in a channel object.
@@ -17,7 +89,7 @@ pub enum ChannelFields {
 }
 
 /// Represents a request to create a new channel in a server.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct ChannelCreate {
     pub name: String,
     #[serde(rename = "type")]
@@ -28,15 +100,50 @@ pub struct ChannelCreate {
     pub nsfw: Option<bool>,
 }
 
+impl ChannelCreate {
+    /// Create a new request for a text channel with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        ChannelCreate {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// The type of the channel.
+    pub fn channel_type(mut self, channel_type: ChannelType) -> Self {
+        self.channel_type = channel_type;
+        self
+    }
+
+    /// The description of the channel.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the channel is NSFW.
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = Some(nsfw);
+        self
+    }
+}
+
+impl<T: Into<String>> From<T> for ChannelCreate {
+    fn from(name: T) -> Self {
+        Self::new(name)
+    }
+}
+
 /// Represents the type of a channel.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub enum ChannelType {
+    #[default]
     Text,
     Voice,
 }
 
 /// Represents a request to update an existing channel in a server.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct ChannelUpdate {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub archived: Option<bool>,
@@ -50,8 +157,18 @@ pub struct ChannelUpdate {
     pub nsfw: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub owner: Option<Id>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub remove: Option<ChannelFields>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<ChannelFields>,
+}
+
+/// Represents the personal "Saved Notes" channel of a user.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct SavedMessagesChannel {
+    /// The ID of the channel.
+    #[serde(rename = "_id")]
+    pub id: Id,
+    /// The ID of the user owning the channel.
+    pub user: Id,
 }
 
 /// Represents a direct message channel between two users.
@@ -67,3 +184,78 @@ pub struct DirectMessageChannel {
     /// The recipients of the direct message.
     pub recipients: [Id; 2],
 }
+
+/// Represents a group channel between multiple users.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct GroupChannel {
+    /// The ID of the channel.
+    #[serde(rename = "_id")]
+    pub id: Id,
+    /// The name of the group.
+    pub name: String,
+    /// The ID of the user owning the group.
+    pub owner: Id,
+    /// The description of the group.
+    pub description: Option<String>,
+    /// The recipients of the group.
+    pub recipients: Vec<Id>,
+    /// The icon of the group.
+    pub icon: Option<Attachment>,
+    /// The ID of the last message in the group.
+    pub last_message_id: Option<Id>,
+    /// The permissions given to members of the group.
+    pub permissions: Option<Permission>,
+    /// Whether the group is NSFW.
+    #[serde(default)]
+    pub nsfw: bool,
+}
+
+/// Represents a text channel in a server.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct TextChannel {
+    /// The ID of the channel.
+    #[serde(rename = "_id")]
+    pub id: Id,
+    /// The ID of the server the channel belongs to.
+    pub server: Id,
+    /// The name of the channel.
+    pub name: String,
+    /// The description of the channel.
+    pub description: Option<String>,
+    /// The icon of the channel.
+    pub icon: Option<Attachment>,
+    /// The ID of the last message in the channel.
+    pub last_message_id: Option<Id>,
+    /// The default permissions override of the channel.
+    pub default_permissions: Option<OverrideField>,
+    /// The permission overrides of roles in the channel.
+    #[serde(default)]
+    pub role_permissions: HashMap<Id, OverrideField>,
+    /// Whether the channel is NSFW.
+    #[serde(default)]
+    pub nsfw: bool,
+}
+
+/// Represents a voice channel in a server.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct VoiceChannel {
+    /// The ID of the channel.
+    #[serde(rename = "_id")]
+    pub id: Id,
+    /// The ID of the server the channel belongs to.
+    pub server: Id,
+    /// The name of the channel.
+    pub name: String,
+    /// The description of the channel.
+    pub description: Option<String>,
+    /// The icon of the channel.
+    pub icon: Option<Attachment>,
+    /// The default permissions override of the channel.
+    pub default_permissions: Option<OverrideField>,
+    /// The permission overrides of roles in the channel.
+    #[serde(default)]
+    pub role_permissions: HashMap<Id, OverrideField>,
+    /// Whether the channel is NSFW.
+    #[serde(default)]
+    pub nsfw: bool,
+}
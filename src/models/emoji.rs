@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{http::HttpClient, models::Id, SeriaResult};
+
+/// Represents a custom emoji.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Emoji {
+    /// The ID of the emoji, which is also the ID of its uploaded file.
+    #[serde(rename = "_id")]
+    pub id: Id,
+    /// What the emoji belongs to.
+    pub parent: EmojiParent,
+    /// The ID of the user who created the emoji.
+    pub creator_id: Id,
+    /// The name of the emoji.
+    pub name: String,
+    /// Whether the emoji is animated.
+    #[serde(default)]
+    pub animated: bool,
+    /// Whether the emoji is NSFW.
+    #[serde(default)]
+    pub nsfw: bool,
+}
+
+impl Emoji {
+    /// The ID of the server the emoji belongs to, if any.
+    pub fn server(&self) -> Option<&Id> {
+        match &self.parent {
+            EmojiParent::Server { id } => Some(id),
+            EmojiParent::Detached => None,
+        }
+    }
+
+    /// Delete this emoji.
+    pub async fn delete(&self, http: &HttpClient) -> SeriaResult {
+        http.delete_emoji(&self.id).await
+    }
+}
+
+/// Represents what an emoji belongs to.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum EmojiParent {
+    /// The emoji belongs to a server.
+    Server { id: Id },
+    /// The emoji no longer belongs to anything.
+    Detached,
+}
+
+/// Represents a request to create a new emoji from an uploaded file.
+#[derive(Clone, Debug, Serialize)]
+pub struct EmojiCreate {
+    pub name: String,
+    pub parent: EmojiParent,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nsfw: Option<bool>,
+}
+
+impl EmojiCreate {
+    /// Create a new request for an emoji with the given name in a server.
+    pub fn new(name: impl Into<String>, server_id: impl Into<Id>) -> Self {
+        EmojiCreate {
+            name: name.into(),
+            parent: EmojiParent::Server {
+                id: server_id.into(),
+            },
+            nsfw: None,
+        }
+    }
+
+    /// Whether the emoji is NSFW.
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = Some(nsfw);
+        self
+    }
+}
+
+/// A lookup of server emojis by name.
+#[derive(Clone, Debug, Default)]
+pub struct EmojiCache {
+    servers: HashMap<Id, HashMap<String, Emoji>>,
+}
+
+impl EmojiCache {
+    /// Create a new, empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fetch the emojis of a server into the cache, replacing any cached for it.
+    pub async fn load(&mut self, http: &HttpClient, server_id: &str) -> SeriaResult {
+        let emojis = http.fetch_server_emojis(server_id).await?;
+
+        self.servers.remove(server_id);
+        for emoji in emojis {
+            self.insert(emoji);
+        }
+
+        Ok(())
+    }
+
+    /// Add an emoji to the cache, replacing any cached emoji with the same ID.
+    ///
+    /// Emojis which do not belong to a server are only removed from the cache.
+    pub fn insert(&mut self, emoji: Emoji) {
+        self.remove(&emoji.id);
+
+        if let Some(server_id) = emoji.server().cloned() {
+            self.servers
+                .entry(server_id)
+                .or_default()
+                .insert(emoji.name.clone(), emoji);
+        }
+    }
+
+    /// Remove an emoji from the cache by its ID.
+    pub fn remove(&mut self, emoji_id: &str) -> Option<Emoji> {
+        self.servers.values_mut().find_map(|emojis| {
+            let name = emojis
+                .iter()
+                .find(|(_, emoji)| emoji.id == emoji_id)
+                .map(|(name, _)| name.clone())?;
+            emojis.remove(&name)
+        })
+    }
+
+    /// Find an emoji of a server by name, given either as `name` or as `:name:`.
+    pub fn resolve(&self, server_id: &str, name: &str) -> Option<&Emoji> {
+        let name = name
+            .strip_prefix(':')
+            .and_then(|name| name.strip_suffix(':'))
+            .unwrap_or(name);
+
+        self.servers.get(server_id)?.get(name)
+    }
+
+    /// Find the ID of an emoji of a server by name, given either as `name` or as `:name:`.
+    pub fn resolve_id(&self, server_id: &str, name: &str) -> Option<&Id> {
+        self.resolve(server_id, name).map(|emoji| &emoji.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn emoji(id: &str, name: &str, parent: EmojiParent) -> Emoji {
+        Emoji {
+            id: id.into(),
+            parent,
+            creator_id: "u".into(),
+            name: name.into(),
+            animated: false,
+            nsfw: false,
+        }
+    }
+
+    fn server(id: &str) -> EmojiParent {
+        EmojiParent::Server { id: id.into() }
+    }
+
+    #[test]
+    fn resolves_by_name_with_or_without_colons() {
+        let mut cache = EmojiCache::new();
+        cache.insert(emoji("e", "party", server("s")));
+
+        assert_eq!(cache.resolve_id("s", "party"), Some(&"e".to_string()));
+        assert_eq!(cache.resolve_id("s", ":party:"), Some(&"e".to_string()));
+        assert_eq!(cache.resolve_id("other", "party"), None);
+    }
+
+    #[test]
+    fn renamed_emoji_replaces_its_old_name() {
+        let mut cache = EmojiCache::new();
+        cache.insert(emoji("e", "old", server("s")));
+        cache.insert(emoji("e", "new", server("s")));
+
+        assert_eq!(cache.resolve("s", ":old:"), None);
+        assert_eq!(cache.resolve_id("s", ":new:"), Some(&"e".to_string()));
+    }
+
+    #[test]
+    fn detached_emoji_is_removed() {
+        let mut cache = EmojiCache::new();
+        cache.insert(emoji("e", "party", server("s")));
+        cache.insert(emoji("e", "party", EmojiParent::Detached));
+
+        assert_eq!(cache.resolve("s", "party"), None);
+    }
+
+    #[test]
+    fn removes_by_id() {
+        let mut cache = EmojiCache::new();
+        cache.insert(emoji("e", "party", server("s")));
+
+        assert_eq!(
+            cache.remove("e").map(|emoji| emoji.name),
+            Some("party".into())
+        );
+        assert_eq!(cache.resolve("s", "party"), None);
+        assert_eq!(cache.remove("e"), None);
+    }
+}
@@ -14,6 +14,7 @@ pub enum ClientEvent {
     Ping { data: usize },
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Deserialize)]
 #[serde(tag = "type")]
 pub enum GatewayEvent {
@@ -0,0 +1,131 @@
+use serde::Deserialize;
+
+use crate::{
+    http::HttpClient,
+    models::{Attachment, Channel, Id, Server, ServerFlags, User},
+    SeriaResult,
+};
+
+/// Represents an invite to a server channel or a group.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type")]
+pub enum Invite {
+    /// An invite to a channel of a server.
+    Server {
+        /// The invite code.
+        #[serde(rename = "_id")]
+        code: String,
+        /// The ID of the server.
+        server: Id,
+        /// The ID of the user who created the invite.
+        creator: Id,
+        /// The ID of the channel the invite points to.
+        channel: Id,
+    },
+    /// An invite to a group.
+    Group {
+        /// The invite code.
+        #[serde(rename = "_id")]
+        code: String,
+        /// The ID of the user who created the invite.
+        creator: Id,
+        /// The ID of the group the invite points to.
+        channel: Id,
+    },
+}
+
+impl Invite {
+    /// The invite code.
+    pub fn code(&self) -> &str {
+        match self {
+            Invite::Server { code, .. } | Invite::Group { code, .. } => code,
+        }
+    }
+
+    /// The ID of the user who created the invite.
+    pub fn creator(&self) -> &Id {
+        match self {
+            Invite::Server { creator, .. } | Invite::Group { creator, .. } => creator,
+        }
+    }
+
+    /// The ID of the channel the invite points to.
+    pub fn channel(&self) -> &Id {
+        match self {
+            Invite::Server { channel, .. } | Invite::Group { channel, .. } => channel,
+        }
+    }
+
+    /// Delete this invite.
+    pub async fn delete(&self, http: &HttpClient) -> SeriaResult {
+        http.delete_invite(self.code()).await
+    }
+}
+
+/// Represents the public information of an invite, as shown before joining.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type")]
+pub enum InviteInfo {
+    /// An invite to a channel of a server.
+    Server {
+        /// The invite code.
+        code: String,
+        /// The ID of the server.
+        server_id: Id,
+        /// The name of the server.
+        server_name: String,
+        /// The icon of the server.
+        server_icon: Option<Box<Attachment>>,
+        /// The banner of the server.
+        server_banner: Option<Box<Attachment>>,
+        /// The flags of the server.
+        server_flags: Option<ServerFlags>,
+        /// The ID of the channel the invite points to.
+        channel_id: Id,
+        /// The name of the channel.
+        channel_name: String,
+        /// The description of the channel.
+        channel_description: Option<String>,
+        /// The name of the user who created the invite.
+        user_name: String,
+        /// The avatar of the user who created the invite.
+        user_avatar: Option<Attachment>,
+        /// The number of members in the server.
+        member_count: i64,
+    },
+    /// An invite to a group.
+    Group {
+        /// The invite code.
+        code: String,
+        /// The ID of the group.
+        channel_id: Id,
+        /// The name of the group.
+        channel_name: String,
+        /// The description of the group.
+        channel_description: Option<String>,
+        /// The name of the user who created the invite.
+        user_name: String,
+        /// The avatar of the user who created the invite.
+        user_avatar: Option<Attachment>,
+    },
+}
+
+/// Represents the response to joining a server or group through an invite.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type")]
+pub enum InviteJoin {
+    /// Joined a server.
+    Server {
+        /// The channels of the server.
+        channels: Vec<Channel>,
+        /// The joined server.
+        server: Box<Server>,
+    },
+    /// Joined a group.
+    Group {
+        /// The joined group.
+        channel: Box<Channel>,
+        /// The members of the group.
+        users: Vec<User>,
+    },
+}
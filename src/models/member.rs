@@ -1,5 +1,117 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    http::HttpClient,
+    models::{Attachment, Id, User},
+    SeriaResult,
+};
+
+/// Represents a member of a server.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Member {
+    /// The server and user the member belongs to.
+    #[serde(rename = "_id")]
+    pub id: MemberCompositeKey,
+    /// When the member joined the server.
+    pub joined_at: String,
+    /// The nickname of the member.
+    pub nickname: Option<String>,
+    /// The server-specific avatar of the member.
+    pub avatar: Option<Attachment>,
+    /// The IDs of the roles assigned to the member.
+    #[serde(default)]
+    pub roles: Vec<Id>,
+    /// When the timeout of the member expires, if timed out.
+    pub timeout: Option<String>,
+}
+
+impl Member {
+    /// Edit this member.
+    pub async fn edit(
+        &self,
+        http: &HttpClient,
+        payload: impl Into<MemberEdit>,
+    ) -> SeriaResult<Self> {
+        http.edit_member(&self.id.server, &self.id.user, payload)
+            .await
+    }
+
+    /// Kick this member from the server.
+    pub async fn kick(&self, http: &HttpClient) -> SeriaResult {
+        http.kick_member(&self.id.server, &self.id.user).await
+    }
+}
+
+/// Represents the members of a server, along with their users.
+#[derive(Clone, Debug, Deserialize)]
+pub struct MemberList {
+    /// The members of the server.
+    pub members: Vec<Member>,
+    /// The users of the members.
+    pub users: Vec<User>,
+}
+
+/// Represents a request to edit a member of a server.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MemberEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<Id>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<Id>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<MemberFields>,
+}
+
+impl MemberEdit {
+    /// Create a new, empty edit request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The nickname of the member.
+    pub fn nickname(mut self, nickname: impl Into<String>) -> Self {
+        self.nickname = Some(nickname.into());
+        self
+    }
+
+    /// The ID of an uploaded avatar.
+    pub fn avatar(mut self, avatar: impl Into<Id>) -> Self {
+        self.avatar = Some(avatar.into());
+        self
+    }
+
+    /// The IDs of the roles assigned to the member, replacing the current ones.
+    pub fn roles(mut self, roles: Vec<Id>) -> Self {
+        self.roles = Some(roles);
+        self
+    }
+
+    /// When the timeout of the member expires, as an ISO 8601 timestamp.
+    pub fn timeout(mut self, timeout: impl Into<String>) -> Self {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// A field to remove from the member.
+    pub fn remove(mut self, field: MemberFields) -> Self {
+        self.remove.push(field);
+        self
+    }
+}
+
+/// Represents the compound key identifying a member.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MemberCompositeKey {
+    /// The ID of the server.
+    pub server: Id,
+    /// The ID of the user.
+    pub user: Id,
+}
+
 /// Represents the fields that can be included in a member object.
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum MemberFields {
@@ -1,11 +1,17 @@
 use bitflags::bitflags;
+use indexmap::{IndexMap, IndexSet};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime};
 
 use crate::{
-    http::HttpClient,
+    http::{FileUpload, HttpClient},
     models::{
-        attachment::Attachment,
+        attachment::{Attachment, AttachmentTag},
         embed::{Embed, EmbedCreate},
+        id_timestamp,
+        member::Member,
+        user::User,
         Id,
     },
     SeriaResult,
@@ -17,9 +23,10 @@ pub struct Message {
     /// The ID of the message.
     #[serde(rename = "_id")]
     pub id: Id,
-    pub nonuce: Option<String>,
+    pub nonce: Option<String>,
     pub channel: Id,
     pub author: String,
+    #[serde(default)]
     pub content: String,
     #[serde(default)]
     pub attachments: Vec<Attachment>,
@@ -29,9 +36,25 @@ pub struct Message {
     pub mentions: Vec<Id>,
     #[serde(default)]
     pub replies: Vec<Id>,
+    /// Whether the message is pinned.
+    #[serde(default)]
+    pub pinned: bool,
+    /// The users who reacted to the message, keyed by emoji in the order they were added.
+    #[serde(default)]
+    pub reactions: IndexMap<String, IndexSet<Id>>,
 }
 
 impl Message {
+    /// The time this message was sent, decoded from its ID.
+    pub fn created_at(&self) -> Option<SystemTime> {
+        id_timestamp(&self.id)
+    }
+
+    /// Delete this message.
+    pub async fn delete(&self, http: &HttpClient) -> SeriaResult {
+        http.delete_message(&self.channel, &self.id).await
+    }
+
     /// Edit this message.
     pub async fn edit(
         &self,
@@ -41,6 +64,28 @@ impl Message {
         http.edit_message(&self.channel, &self.id, payload).await
     }
 
+    /// React to this message with an emoji.
+    pub async fn react(&self, http: &HttpClient, emoji: &str) -> SeriaResult {
+        http.add_reaction(&self.channel, &self.id, emoji).await
+    }
+
+    /// Pin this message.
+    pub async fn pin(&self, http: &HttpClient) -> SeriaResult {
+        http.pin_message(&self.channel, &self.id).await
+    }
+
+    /// Unpin this message.
+    pub async fn unpin(&self, http: &HttpClient) -> SeriaResult {
+        http.unpin_message(&self.channel, &self.id).await
+    }
+
+    /// Mark the channel as read up to this message.
+    ///
+    /// Requires a user session, see [`HttpConfig::session`](crate::http::HttpConfig::session).
+    pub async fn ack(&self, http: &HttpClient) -> SeriaResult {
+        http.ack_message(&self.channel, &self.id).await
+    }
+
     /// Reply to the message corresponding to this instance.
     pub async fn reply(
         &self,
@@ -73,14 +118,16 @@ pub struct MessageSend {
 
 bitflags! {
     /// Represents the flags associated with a message.
-    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+    #[derive(Clone, Debug, Default, PartialEq)]
     pub struct MessageFlags: u32 {
         const SurpressNotifications = 1;
         const MentionsEveryone = 2;
-        const MentionsOnline = 3;
+        const MentionsOnline = 4;
     }
 }
 
+bitflags_serde!(MessageFlags: u32);
+
 // Message display masquerade information.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMasquerade {
@@ -116,6 +163,83 @@ impl<T: Into<String>> From<T> for MessageSend {
     }
 }
 
+/// Builds a message whose local files are uploaded as attachments when it is sent.
+#[derive(Debug, Default)]
+pub struct MessageBuilder {
+    message: MessageSend,
+    files: Vec<FileUpload>,
+}
+
+impl MessageBuilder {
+    /// Create a new message with the given content.
+    pub fn new(content: impl Into<String>) -> Self {
+        MessageBuilder {
+            message: content.into().into(),
+            files: Vec::new(),
+        }
+    }
+
+    /// Attach a file, uploaded when the message is sent.
+    pub fn file(mut self, file: FileUpload) -> Self {
+        self.files.push(file);
+        self
+    }
+
+    /// Attach a file which was already uploaded.
+    pub fn attachment(mut self, attachment_id: impl Into<Id>) -> Self {
+        self.message.attachments.push(attachment_id.into());
+        self
+    }
+
+    /// Add an embed to the message.
+    pub fn embed(mut self, embed: EmbedCreate) -> Self {
+        self.message.embeds.push(embed);
+        self
+    }
+
+    /// Reply to a message.
+    pub fn reply(mut self, message_id: impl Into<Id>, mention: bool) -> Self {
+        self.message.replies.push(MessageReplyIntent {
+            id: message_id.into(),
+            mention,
+            fail_if_not_exists: true,
+        });
+        self
+    }
+
+    /// The name and avatar to display instead of the author's.
+    pub fn masquerade(mut self, masquerade: MessageMasquerade) -> Self {
+        self.message.masquerade = Some(masquerade);
+        self
+    }
+
+    /// The flags of the message.
+    pub fn flags(mut self, flags: MessageFlags) -> Self {
+        self.message.flags = Some(flags);
+        self
+    }
+
+    /// Upload the attached files and send the message in the specified channel.
+    pub async fn send(self, http: &HttpClient, channel_id: &str) -> SeriaResult<Message> {
+        let mut message = self.message;
+        for file in self.files {
+            let id = http.upload_file(AttachmentTag::Attachments, file).await?;
+            message.attachments.push(id);
+        }
+
+        http.send_message(channel_id, message).await
+    }
+}
+
+impl From<MessageSend> for MessageBuilder {
+    fn from(message: MessageSend) -> Self {
+        MessageBuilder {
+            message,
+            files: Vec::new(),
+        }
+    }
+}
+
 /// Represents a request to edit an existing message.
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct MessageEdit {
@@ -124,3 +248,372 @@ pub struct MessageEdit {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub embeds: Vec<EmbedCreate>,
 }
+
+/// Represents the order in which messages are returned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub enum MessageSort {
+    /// Sort by relevance to the search query.
+    Relevance,
+    /// Sort by the newest messages first.
+    #[default]
+    Latest,
+    /// Sort by the oldest messages first.
+    Oldest,
+}
+
+/// Represents a query for a page of messages in a channel.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MessageQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Id>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Id>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<MessageSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nearby: Option<Id>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_users: Option<bool>,
+}
+
+impl MessageQuery {
+    /// Create a new, empty query.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The maximum number of messages to fetch, between 1 and 100.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only fetch messages sent before this message.
+    pub fn before(mut self, before: impl Into<Id>) -> Self {
+        self.before = Some(before.into());
+        self
+    }
+
+    /// Only fetch messages sent after this message.
+    pub fn after(mut self, after: impl Into<Id>) -> Self {
+        self.after = Some(after.into());
+        self
+    }
+
+    /// The order in which messages are returned.
+    pub fn sort(mut self, sort: MessageSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Fetch messages around this message, ignoring `before`, `after` and `sort`.
+    pub fn nearby(mut self, nearby: impl Into<Id>) -> Self {
+        self.nearby = Some(nearby.into());
+        self
+    }
+
+    /// Whether to include the authors of the messages in the response.
+    pub fn include_users(mut self, include_users: bool) -> Self {
+        self.include_users = Some(include_users);
+        self
+    }
+}
+
+/// Represents a search for messages in a channel.
+///
+/// Either a query or the pinned filter must be given.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MessageSearch {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<Id>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Id>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<MessageSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_users: Option<bool>,
+}
+
+impl MessageSearch {
+    /// Create a new search for messages matching the query.
+    pub fn new(query: impl Into<String>) -> Self {
+        MessageSearch {
+            query: Some(query.into()),
+            ..Default::default()
+        }
+    }
+
+    /// The full-text query to search for.
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Only search messages which are, or are not, pinned.
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = Some(pinned);
+        self
+    }
+
+    /// The maximum number of messages to fetch, between 1 and 100.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only search messages sent before this message.
+    pub fn before(mut self, before: impl Into<Id>) -> Self {
+        self.before = Some(before.into());
+        self
+    }
+
+    /// Only search messages sent after this message.
+    pub fn after(mut self, after: impl Into<Id>) -> Self {
+        self.after = Some(after.into());
+        self
+    }
+
+    /// The order in which messages are returned.
+    pub fn sort(mut self, sort: MessageSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Whether to include the authors of the messages in the response.
+    pub fn include_users(mut self, include_users: bool) -> Self {
+        self.include_users = Some(include_users);
+        self
+    }
+}
+
+impl<T: Into<String>> From<T> for MessageSearch {
+    fn from(query: T) -> Self {
+        Self::new(query)
+    }
+}
+
+/// Represents a page of messages, along with their authors when requested.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum BulkMessageResponse {
+    JustMessages(Vec<Message>),
+    MessagesAndUsers {
+        messages: Vec<Message>,
+        users: Vec<User>,
+        #[serde(default)]
+        members: Vec<Member>,
+    },
+}
+
+impl BulkMessageResponse {
+    /// The messages in the response.
+    pub fn messages(&self) -> &[Message] {
+        match self {
+            BulkMessageResponse::JustMessages(messages) => messages,
+            BulkMessageResponse::MessagesAndUsers { messages, .. } => messages,
+        }
+    }
+
+    /// Take the messages out of the response, discarding any users or members.
+    pub fn into_messages(self) -> Vec<Message> {
+        match self {
+            BulkMessageResponse::JustMessages(messages) => messages,
+            BulkMessageResponse::MessagesAndUsers { messages, .. } => messages,
+        }
+    }
+}
+
+/// Represents the query used to remove reactions from a message.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ReactionRemove {
+    /// The user whose reaction is removed, instead of the current user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<Id>,
+    /// Whether to remove the reactions of every user with this emoji.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_all: Option<bool>,
+}
+
+/// Represents a request to delete multiple messages at once.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BulkMessageDelete {
+    pub ids: Vec<Id>,
+}
+
+/// Describes which messages are deleted when purging a channel.
+///
+/// Every criterion which is set must match for a message to be deleted.
+#[derive(Clone, Debug, Default)]
+pub struct PurgeFilter {
+    /// Only delete messages sent by this user.
+    pub author: Option<Id>,
+    /// Only delete messages whose content matches this pattern.
+    pub content: Option<Regex>,
+    /// Only delete messages sent within this duration.
+    pub max_age: Option<Duration>,
+    /// Only delete messages which have, or do not have, attachments.
+    pub has_attachments: Option<bool>,
+    /// The maximum number of messages to delete.
+    pub limit: Option<usize>,
+}
+
+impl PurgeFilter {
+    /// Create a new filter matching every message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only delete messages sent by this user.
+    pub fn author(mut self, author: impl Into<Id>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// Only delete messages whose content matches this pattern.
+    pub fn content(mut self, content: Regex) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Only delete messages sent within this duration.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Only delete messages which have, or do not have, attachments.
+    pub fn has_attachments(mut self, has_attachments: bool) -> Self {
+        self.has_attachments = Some(has_attachments);
+        self
+    }
+
+    /// The maximum number of messages to delete.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Whether the message matches every criterion of this filter, except its age.
+    pub fn matches(&self, message: &Message) -> bool {
+        if let Some(author) = &self.author {
+            if *author != message.author {
+                return false;
+            }
+        }
+
+        if let Some(content) = &self.content {
+            if !content.is_match(&message.content) {
+                return false;
+            }
+        }
+
+        if let Some(has_attachments) = self.has_attachments {
+            if has_attachments == message.attachments.is_empty() {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::time::UNIX_EPOCH};
+
+    fn message(author: &str, content: &str, attachments: usize) -> Message {
+        let attachment = serde_json::json!({
+            "_id": "a",
+            "tag": "attachments",
+            "filename": "file.txt",
+            "metadata": { "type": "File" },
+            "content_type": "text/plain",
+            "size": 1,
+        });
+
+        serde_json::from_value(serde_json::json!({
+            "_id": "01ARZ3NDEKTSV4RRFFQ69G5FAV",
+            "channel": "c",
+            "author": author,
+            "content": content,
+            "attachments": vec![attachment; attachments],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        let filter = PurgeFilter::new();
+
+        assert!(filter.matches(&message("u", "", 0)));
+        assert!(filter.matches(&message("v", "hello", 2)));
+    }
+
+    #[test]
+    fn filters_by_author() {
+        let filter = PurgeFilter::new().author("u");
+
+        assert!(filter.matches(&message("u", "hello", 0)));
+        assert!(!filter.matches(&message("v", "hello", 0)));
+    }
+
+    #[test]
+    fn filters_by_content() {
+        let filter = PurgeFilter::new().content(Regex::new("^spam").unwrap());
+
+        assert!(filter.matches(&message("u", "spam and eggs", 0)));
+        assert!(!filter.matches(&message("u", "eggs and spam", 0)));
+        assert!(!filter.matches(&message("u", "", 0)));
+    }
+
+    #[test]
+    fn filters_by_attachments() {
+        let with = PurgeFilter::new().has_attachments(true);
+        let without = PurgeFilter::new().has_attachments(false);
+
+        assert!(with.matches(&message("u", "", 1)));
+        assert!(!with.matches(&message("u", "", 0)));
+        assert!(without.matches(&message("u", "", 0)));
+        assert!(!without.matches(&message("u", "", 1)));
+    }
+
+    #[test]
+    fn requires_every_criterion() {
+        let filter = PurgeFilter::new()
+            .author("u")
+            .content(Regex::new("spam").unwrap())
+            .has_attachments(false);
+
+        assert!(filter.matches(&message("u", "spam", 0)));
+        assert!(!filter.matches(&message("v", "spam", 0)));
+        assert!(!filter.matches(&message("u", "eggs", 0)));
+        assert!(!filter.matches(&message("u", "spam", 1)));
+    }
+
+    #[test]
+    fn flags_serialize_as_bits() {
+        let message = MessageBuilder::new("hello")
+            .flags(MessageFlags::SurpressNotifications | MessageFlags::MentionsOnline)
+            .message;
+
+        assert_eq!(serde_json::to_value(&message).unwrap()["flags"], 5);
+    }
+
+    #[test]
+    fn created_at_is_decoded_from_the_id() {
+        let created_at = message("u", "", 0).created_at();
+
+        assert_eq!(
+            created_at,
+            Some(UNIX_EPOCH + Duration::from_millis(1469922850259))
+        );
+    }
+}
@@ -1,25 +1,95 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 pub use {
     attachment::*,
     bot::*,
     channel::*,
     embed::*,
+    emoji::*,
     event::*,
+    invite::*,
     member::*,
     message::*,
     permission::*,
+    safety::*,
     server::*,
     user::*,
+    webhook::*,
 };
 
+/// Implements `Serialize` and `Deserialize` for a bitflags type as its raw bits,
+/// which is how the API represents flags.
+macro_rules! bitflags_serde {
+    ($name:ident: $bits:ty) => {
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.bits().serialize(serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <$bits>::deserialize(deserializer).map(Self::from_bits_retain)
+            }
+        }
+    };
+}
+
 mod attachment;
 mod bot;
 mod channel;
 mod embed;
+mod emoji;
 mod event;
+mod invite;
 mod member;
 mod message;
 mod permission;
+mod safety;
 mod server;
 mod user;
+mod webhook;
 
 pub type Id = String;
+
+/// Returns the time an object was created, decoded from the timestamp of its ULID.
+pub fn id_timestamp(id: &str) -> Option<SystemTime> {
+    const ALPHABET: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    let millis = id.get(..10)?.bytes().try_fold(0u64, |millis, byte| {
+        let value = ALPHABET.iter().position(|c| *c == byte.to_ascii_uppercase())?;
+        Some(millis << 5 | value as u64)
+    })?;
+
+    UNIX_EPOCH.checked_add(Duration::from_millis(millis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_ulid_timestamp() {
+        let expected = UNIX_EPOCH + Duration::from_millis(1469922850259);
+
+        assert_eq!(id_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FAV"), Some(expected));
+        assert_eq!(id_timestamp("01arz3ndektsv4rrffq69g5fav"), Some(expected));
+    }
+
+    #[test]
+    fn only_reads_the_timestamp_part() {
+        let expected = UNIX_EPOCH + Duration::from_millis(1469922850259);
+
+        assert_eq!(id_timestamp("01ARZ3NDEK"), Some(expected));
+        assert_eq!(id_timestamp("01ARZ3NDEK!!!"), Some(expected));
+    }
+
+    #[test]
+    fn rejects_short_or_invalid_ids() {
+        assert_eq!(id_timestamp(""), None);
+        assert_eq!(id_timestamp("01ARZ3NDE"), None);
+        assert_eq!(id_timestamp("01ARZ3NDEU"), None);
+        assert_eq!(id_timestamp("01ARZ-NDEKTSV4RRFFQ69G5FAV"), None);
+        assert_eq!(id_timestamp("01ARZ3ND\u{e9}KTSV4RRFFQ69G5FAV"), None);
+    }
+}
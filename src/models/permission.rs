@@ -96,7 +96,7 @@ impl UserPermission {
 }
 
 /// Raw representation of permission overrides used in storage.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct OverrideField {
     /// Bits for allowed permissions.
     pub a: Permission,
@@ -105,10 +105,62 @@ pub struct OverrideField {
 }
 
 /// Processed permission override model.
-#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct Override {
     /// Permissions granted explicitly.
     pub allow: Permission,
     /// Permissions explicitly denied.
     pub deny: Permission,
 }
+
+impl From<Override> for OverrideField {
+    fn from(value: Override) -> Self {
+        OverrideField {
+            a: value.allow,
+            d: value.deny,
+        }
+    }
+}
+
+impl From<OverrideField> for Override {
+    fn from(value: OverrideField) -> Self {
+        Override {
+            allow: value.a,
+            deny: value.d,
+        }
+    }
+}
+
+/// Default permissions of a channel, given as a plain permission set for groups
+/// or as an override for server channels.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum DefaultPermissions {
+    Value(Permission),
+    Override(Override),
+}
+
+impl From<Permission> for DefaultPermissions {
+    fn from(value: Permission) -> Self {
+        DefaultPermissions::Value(value)
+    }
+}
+
+impl From<Override> for DefaultPermissions {
+    fn from(value: Override) -> Self {
+        DefaultPermissions::Override(value)
+    }
+}
+
+impl From<OverrideField> for DefaultPermissions {
+    fn from(value: OverrideField) -> Self {
+        DefaultPermissions::Override(value.into())
+    }
+}
+
+/// Represents a request to set the permissions of a role or of everyone.
+#[derive(Serialize, Debug, Clone)]
+pub struct PermissionsUpdate<T> {
+    /// The permissions to set.
+    pub permissions: T,
+}
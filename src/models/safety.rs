@@ -0,0 +1,115 @@
+use serde::Serialize;
+
+use crate::models::Id;
+
+/// Represents a report of content to the platform moderation team.
+#[derive(Clone, Debug, Serialize)]
+pub struct ContentReport {
+    /// The reported content.
+    pub content: ReportedContent,
+    /// Additional context for the moderators.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub additional_context: String,
+}
+
+impl ContentReport {
+    /// Report a message.
+    pub fn message(message_id: impl Into<Id>, reason: ContentReportReason) -> Self {
+        ReportedContent::Message {
+            id: message_id.into(),
+            report_reason: reason,
+        }
+        .into()
+    }
+
+    /// Report a server.
+    pub fn server(server_id: impl Into<Id>, reason: ContentReportReason) -> Self {
+        ReportedContent::Server {
+            id: server_id.into(),
+            report_reason: reason,
+        }
+        .into()
+    }
+
+    /// Report a user, optionally pointing at the message which prompted the report.
+    pub fn user(
+        user_id: impl Into<Id>,
+        reason: UserReportReason,
+        message_id: Option<&str>,
+    ) -> Self {
+        ReportedContent::User {
+            id: user_id.into(),
+            report_reason: reason,
+            message_id: message_id.map(Into::into),
+        }
+        .into()
+    }
+
+    /// Additional context for the moderators.
+    pub fn additional_context(mut self, context: impl Into<String>) -> Self {
+        self.additional_context = context.into();
+        self
+    }
+}
+
+impl From<ReportedContent> for ContentReport {
+    fn from(content: ReportedContent) -> Self {
+        ContentReport {
+            content,
+            additional_context: String::new(),
+        }
+    }
+}
+
+/// Represents the content targeted by a report.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum ReportedContent {
+    Message {
+        id: Id,
+        report_reason: ContentReportReason,
+    },
+    Server {
+        id: Id,
+        report_reason: ContentReportReason,
+    },
+    User {
+        id: Id,
+        report_reason: UserReportReason,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        message_id: Option<Id>,
+    },
+}
+
+/// Represents the reason a message or server is reported for.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub enum ContentReportReason {
+    #[default]
+    NoneSpecified,
+    Illegal,
+    IllegalGoods,
+    IllegalExtortion,
+    IllegalPornography,
+    IllegalHacking,
+    ExtremeViolence,
+    PromotesHarm,
+    UnsolicitedSpam,
+    Raid,
+    SpamAbuse,
+    ScamsFraud,
+    Malware,
+    Harassment,
+}
+
+/// Represents the reason a user is reported for.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub enum UserReportReason {
+    #[default]
+    NoneSpecified,
+    UnsolicitedSpam,
+    SpamAbuse,
+    InappropriateProfile,
+    Impersonation,
+    BanEvasion,
+    Underage,
+}
@@ -2,10 +2,16 @@ use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, hash::Hash};
 
-use crate::models::{
-    attachment::Attachment,
-    permission::{OverrideField, Permission},
-    Id,
+use crate::{
+    http::HttpClient,
+    models::{
+        attachment::Attachment,
+        channel::Channel,
+        member::MemberCompositeKey,
+        permission::{OverrideField, Permission},
+        Id,
+    },
+    SeriaResult,
 };
 
 /// Represents a role in a server, which defines permissions and attributes for members.
@@ -30,6 +36,98 @@ pub struct Role {
     pub rank: Option<i64>,
 }
 
+/// Represents the response to creating a new role.
+#[derive(Clone, Debug, Deserialize)]
+pub struct RoleCreateResponse {
+    /// The ID of the created role.
+    pub id: Id,
+
+    /// The created role.
+    pub role: Role,
+}
+
+/// Represents a request to create a new role in a server.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct RoleCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rank: Option<i64>,
+}
+
+impl RoleCreate {
+    /// Create a new request for a role with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        RoleCreate {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// The rank of the role, lower ranks being higher in the hierarchy.
+    pub fn rank(mut self, rank: i64) -> Self {
+        self.rank = Some(rank);
+        self
+    }
+}
+
+impl<T: Into<String>> From<T> for RoleCreate {
+    fn from(name: T) -> Self {
+        Self::new(name)
+    }
+}
+
+/// Represents a request to edit an existing role.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct RoleEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colour: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hoist: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rank: Option<i64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<RoleFields>,
+}
+
+impl RoleEdit {
+    /// Create a new, empty edit request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The name of the role.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The colour of the role, as a CSS colour.
+    pub fn colour(mut self, colour: impl Into<String>) -> Self {
+        self.colour = Some(colour.into());
+        self
+    }
+
+    /// Whether the role is displayed separately in the member list.
+    pub fn hoist(mut self, hoist: bool) -> Self {
+        self.hoist = Some(hoist);
+        self
+    }
+
+    /// The rank of the role, where lower ranks are more important.
+    pub fn rank(mut self, rank: i64) -> Self {
+        self.rank = Some(rank);
+        self
+    }
+
+    /// A field to remove from the role.
+    pub fn remove(mut self, field: RoleFields) -> Self {
+        self.remove.push(field);
+        self
+    }
+}
+
 /// Represents the fields that can be included in a role object.
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum RoleFields {
@@ -80,13 +178,15 @@ pub struct SystemMessageChannels {
 
 bitflags! {
     /// Represents the flags associated with a server.
-    #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+    #[derive(Clone, Debug, Default, PartialEq)]
     pub struct ServerFlags: u32 {
         const VerifiedServer = 1;
         const OfficialServer = 2;
     }
 }
 
+bitflags_serde!(ServerFlags: u32);
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Server {
     /// Whether the server has analytics enabled.
@@ -98,7 +198,7 @@ pub struct Server {
     pub banner: Option<Attachment>,
 
     /// The categories in the server.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub categories: Vec<Category>,
 
     /// The channels within the server.
@@ -108,7 +208,8 @@ pub struct Server {
     pub default_permissions: Permission,
 
     /// The description of the server.
-    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 
     /// Whether the server is discoverable.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -144,50 +245,231 @@ pub struct Server {
     pub system_messages: Option<SystemMessageChannels>,
 }
 
-///
+impl Server {
+    /// Edit this server.
+    pub async fn edit(
+        &self,
+        http: &HttpClient,
+        payload: impl Into<ServerEdit>,
+    ) -> SeriaResult<Self> {
+        http.edit_server(&self.id, payload).await
+    }
+
+    /// Delete this server if owned, otherwise leave it.
+    pub async fn delete(&self, http: &HttpClient) -> SeriaResult {
+        http.delete_server(&self.id).await
+    }
+}
+
+/// Represents a ban placed on a user in a server.
 #[derive(Clone, Debug, Deserialize)]
 pub struct ServerBan {
-    /// The ID of the user who is banned.
+    /// The server and user the ban applies to.
     #[serde(rename = "_id")]
-    pub user: Id,
+    pub id: MemberCompositeKey,
 
     /// The reason for the ban, if provided.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
 }
 
+/// Represents the partial information of a banned user.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BannedUser {
+    /// The ID of the user.
+    #[serde(rename = "_id")]
+    pub id: Id,
+
+    /// The username of the user.
+    pub username: String,
+
+    /// The discriminator of the user.
+    #[serde(default)]
+    pub discriminator: String,
+
+    /// The avatar of the user.
+    pub avatar: Option<Attachment>,
+}
+
+/// Represents the bans of a server, along with the users they apply to.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BanList {
+    /// The users who are banned.
+    pub users: Vec<BannedUser>,
+
+    /// The bans of the server.
+    pub bans: Vec<ServerBan>,
+}
+
+/// Represents a request to ban a user from a server.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct BanCreate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Represents the response to creating a new server.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServerCreateResponse {
+    /// The created server.
+    pub server: Server,
+    /// The channels created along with the server.
+    pub channels: Vec<Channel>,
+}
+
 /// Represents a request to create a new server.
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct ServerCreate {
+    pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    description: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    name: Option<String>,
+    pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    nsfw: Option<bool>,
+    pub nsfw: Option<bool>,
+}
+
+impl ServerCreate {
+    /// Create a new request for a server with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        ServerCreate {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// The description of the server.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the server is NSFW.
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = Some(nsfw);
+        self
+    }
+}
+
+impl<T: Into<String>> From<T> for ServerCreate {
+    fn from(name: T) -> Self {
+        Self::new(name)
+    }
 }
 
 /// Represents a request to edit an existing server.
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct ServerEdit {
     #[serde(skip_serializing_if = "Option::is_none")]
-    analyrics: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    banner: Option<Attachment>,
+    pub analytics: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    categories: Option<Vec<Category>>,
+    pub banner: Option<Id>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    description: Option<String>,
+    pub categories: Option<Vec<Category>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    discoverable: Option<bool>,
+    pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    flags: Option<ServerFlags>,
+    pub discoverable: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    icon: Option<Attachment>,
+    pub flags: Option<ServerFlags>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    name: Option<String>,
+    pub icon: Option<Id>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    remove: Option<ServerFields>,
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<ServerFields>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    system_messages: Option<SystemMessageChannels>,
+    pub system_messages: Option<SystemMessageChannels>,
+}
+
+impl ServerEdit {
+    /// Create a new, empty edit request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether analytics are enabled for the server.
+    pub fn analytics(mut self, analytics: bool) -> Self {
+        self.analytics = Some(analytics);
+        self
+    }
+
+    /// The ID of an uploaded banner.
+    pub fn banner(mut self, banner: impl Into<Id>) -> Self {
+        self.banner = Some(banner.into());
+        self
+    }
+
+    /// The categories of the server.
+    pub fn categories(mut self, categories: Vec<Category>) -> Self {
+        self.categories = Some(categories);
+        self
+    }
+
+    /// The description of the server.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the server is discoverable.
+    pub fn discoverable(mut self, discoverable: bool) -> Self {
+        self.discoverable = Some(discoverable);
+        self
+    }
+
+    /// The flags of the server.
+    pub fn flags(mut self, flags: ServerFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// The ID of an uploaded icon.
+    pub fn icon(mut self, icon: impl Into<Id>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// The name of the server.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// A field to remove from the server.
+    pub fn remove(mut self, field: ServerFields) -> Self {
+        self.remove.push(field);
+        self
+    }
+
+    /// The system message channels of the server.
+    pub fn system_messages(mut self, system_messages: SystemMessageChannels) -> Self {
+        self.system_messages = Some(system_messages);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINIMAL_SERVER: &str =
+        r#"{"_id":"01H","owner":"u","name":"n","channels":[],"default_permissions":0}"#;
+
+    #[test]
+    fn decodes_minimal_server() {
+        let server: Server = serde_json::from_str(MINIMAL_SERVER).unwrap();
+
+        assert_eq!(server.id, "01H");
+        assert!(server.categories.is_empty());
+        assert!(server.roles.is_empty());
+        assert_eq!(server.description, None);
+    }
+
+    #[test]
+    fn decodes_create_response_with_minimal_server() {
+        let body = format!(r#"{{"server":{},"channels":[]}}"#, MINIMAL_SERVER);
+        let response: ServerCreateResponse = serde_json::from_str(&body).unwrap();
+
+        assert_eq!(response.server.name, "n");
+        assert!(response.channels.is_empty());
+    }
 }
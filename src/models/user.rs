@@ -1,7 +1,11 @@
 use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
 
-use crate::{http::HttpClient, models::{Attachment, Id}, SeriaResult};
+use crate::{
+    http::HttpClient,
+    models::{Attachment, Id, Message, MessageSend},
+    SeriaResult,
+};
 
 /// Represents a user.
 #[derive(Clone, Debug, Deserialize, PartialEq)]
@@ -32,13 +36,33 @@ pub struct User {
     /// The flags of the user.
     #[serde(default)]
     pub flags: UserFlags,
+    /// Information about the bot, if the user is one.
+    pub bot: Option<BotInformation>,
 }
 
 impl User {
+    /// Whether the user is a bot.
+    pub fn is_bot(&self) -> bool {
+        self.bot.is_some()
+    }
+
     /// Edit this user.
-    pub async fn edit(&self, http: &HttpClient, payload: impl Into<UserUpdate>) -> SeriaResult<Self> {
+    pub async fn edit(
+        &self,
+        http: &HttpClient,
+        payload: impl Into<UserUpdate>,
+    ) -> SeriaResult<Self> {
         http.edit_user(&self.id, payload.into()).await
     }
+
+    /// Send a direct message to this user.
+    pub async fn send_dm(
+        &self,
+        http: &HttpClient,
+        payload: impl Into<MessageSend>,
+    ) -> SeriaResult<Message> {
+        http.send_dm(&self.id, payload).await
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
@@ -84,6 +108,15 @@ pub struct UserProfileUpdate {
     pub background: Option<Id>,
 }
 
+/// Represents the profile of a user.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct UserProfile {
+    /// The text content of the profile.
+    pub content: Option<String>,
+    /// The background image of the profile.
+    pub background: Option<Attachment>,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct UserRelationship {
     #[serde(rename = "_id")]
@@ -132,8 +165,7 @@ pub enum UserFields {
 }
 
 bitflags! {
-    #[derive(Clone, Debug, PartialEq, Deserialize, Default)]
-    #[serde(transparent)]
+    #[derive(Clone, Debug, PartialEq, Default)]
     pub struct UserBadges: u32 {
         const Developer = 1;
         const Translator = 2;
@@ -150,8 +182,7 @@ bitflags! {
 }
 
 bitflags! {
-    #[derive(Clone, Debug, PartialEq, Deserialize, Default)]
-    #[serde(transparent)]
+    #[derive(Clone, Debug, PartialEq, Default)]
     pub struct UserFlags: u32 {
         const Suspended = 1;
         const Deleted = 2;
@@ -160,20 +191,30 @@ bitflags! {
     }
 }
 
+bitflags_serde!(UserBadges: u32);
+bitflags_serde!(UserFlags: u32);
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct FlagResponse {
     pub flags: i32,
 }
 
+#[derive(Clone, Debug, Deserialize)]
 pub struct MutualResponse {
-    pub users: Vec<String>,
-    pub servers: Vec<String>,
+    pub users: Vec<Id>,
+    pub servers: Vec<Id>,
+    #[serde(default)]
+    pub channels: Vec<Id>,
 }
 
+/// Represents the bot-specific information of a user.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
 pub struct BotInformation {
-    pub owner: String,
+    /// The ID of the user owning the bot.
+    pub owner: Id,
 }
 
+#[derive(Clone, Debug, Serialize)]
 pub struct SendFriendRequest {
     pub username: String,
 }
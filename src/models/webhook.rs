@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    http::HttpClient,
+    models::{Attachment, Id, Permission},
+    SeriaResult,
+};
+
+/// Represents a webhook which can send messages to a channel.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Webhook {
+    /// The ID of the webhook.
+    pub id: Id,
+    /// The name of the webhook.
+    pub name: String,
+    /// The avatar of the webhook.
+    pub avatar: Option<Attachment>,
+    /// The ID of the user who created the webhook, if known.
+    pub creator_id: Option<Id>,
+    /// The ID of the channel the webhook sends messages to.
+    pub channel_id: Id,
+    /// The permissions of the webhook.
+    pub permissions: Permission,
+    /// The token of the webhook, if known.
+    pub token: Option<String>,
+}
+
+impl Webhook {
+    /// Edit this webhook.
+    pub async fn edit(
+        &self,
+        http: &HttpClient,
+        payload: impl Into<WebhookEdit>,
+    ) -> SeriaResult<Self> {
+        http.edit_webhook(&self.id, payload).await
+    }
+
+    /// Delete this webhook.
+    pub async fn delete(&self, http: &HttpClient) -> SeriaResult {
+        http.delete_webhook(&self.id).await
+    }
+}
+
+/// Represents the fields that can be included in a webhook object.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum WebhookFields {
+    Avatar,
+}
+
+/// Represents a request to create a new webhook in a channel.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct WebhookCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<Id>,
+}
+
+impl WebhookCreate {
+    /// Create a new request for a webhook with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        WebhookCreate {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// The ID of an uploaded avatar for the webhook.
+    pub fn avatar(mut self, avatar: impl Into<Id>) -> Self {
+        self.avatar = Some(avatar.into());
+        self
+    }
+}
+
+impl<T: Into<String>> From<T> for WebhookCreate {
+    fn from(name: T) -> Self {
+        Self::new(name)
+    }
+}
+
+/// Represents a request to edit an existing webhook.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct WebhookEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<Id>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permission>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<WebhookFields>,
+}
+
+impl WebhookEdit {
+    /// Create a new empty edit request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The new name of the webhook.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The ID of an uploaded avatar for the webhook.
+    pub fn avatar(mut self, avatar: impl Into<Id>) -> Self {
+        self.avatar = Some(avatar.into());
+        self
+    }
+
+    /// The new permissions of the webhook.
+    pub fn permissions(mut self, permissions: Permission) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    /// Remove a field from the webhook.
+    pub fn remove(mut self, field: WebhookFields) -> Self {
+        self.remove.push(field);
+        self
+    }
+}